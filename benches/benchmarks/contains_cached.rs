@@ -15,7 +15,7 @@ pub fn benches(criterion: &mut Criterion) {
     let mut rng = rand::thread_rng();
 
     let mut input = [0u8; 256];
-    for i in 0..=u8::max_value() {
+    for i in 0..=u8::MAX {
         input[i as usize] = i;
     }
 
@@ -192,7 +192,7 @@ pub fn benches(criterion: &mut Criterion) {
                         black_box((rng.gen::<u8>(), vec))
                     },
                     |(byte, vec)| {
-                        black_box(vec.binary_search(&byte).is_ok());
+                        black_box(vec.binary_search(byte).is_ok());
                     },
                     BatchSize::SmallInput,
                 )
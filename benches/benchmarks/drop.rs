@@ -4,6 +4,8 @@ use std::collections::{BTreeSet, HashSet};
 use crate::util::{self, hash::HashbrownSet, Bool256, Rand};
 use byte_set::ByteSet;
 
+// Dropping is the operation being measured, even for `Copy` types.
+#[allow(dropping_copy_types, clippy::drop_non_drop)]
 pub fn benches(criterion: &mut Criterion) {
     let mut group = criterion.benchmark_group("Drop");
 
@@ -31,6 +31,17 @@ pub fn benches(criterion: &mut Criterion) {
             )
         });
 
+        group.bench_function(
+            BenchmarkId::new("ByteSet::from_slice", size),
+            |b| {
+                b.iter_batched_ref(
+                    || black_box(shuffled_bytes(&mut rng)),
+                    |bytes| black_box(ByteSet::from_slice(&bytes[..size])),
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+
         group.bench_function(BenchmarkId::new("[bool; 256]", size), |b| {
             b.iter_batched_ref(
                 || {
@@ -182,7 +182,7 @@ pub fn benches(criterion: &mut Criterion) {
                     },
                     |(byte, fixed_bit_set)| {
                         if fixed_bit_set.len() != 0 {
-                            fixed_bit_set.set(*byte as usize, false);
+                            fixed_bit_set.set(*byte, false);
                         }
                         black_box(fixed_bit_set);
                     },
@@ -17,7 +17,7 @@ pub trait Cast<T> {
 
 impl Cast<u8> for usize {
     fn saturating_cast(self) -> u8 {
-        u8::try_from(self).unwrap_or(u8::max_value())
+        u8::try_from(self).unwrap_or(u8::MAX)
     }
 }
 
@@ -10,7 +10,7 @@ use std::{
 /// Returns an array of bytes that has been shuffled.
 pub fn shuffled_bytes<R: Rng>(rng: &mut R) -> [u8; 256] {
     let mut input = [0u8; 256];
-    for i in 0..=u8::max_value() {
+    for i in 0..=u8::MAX {
         input[i as usize] = i;
     }
     input.shuffle(rng);
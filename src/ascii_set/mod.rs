@@ -0,0 +1,312 @@
+//! A set of ASCII bytes that is half the size of [`ByteSet`] and can be used
+//! with `str` safely.
+//!
+//! See [`AsciiSet`] for more info.
+//!
+//! [`ByteSet`]: ../struct.ByteSet.html
+//! [`AsciiSet`]: struct.AsciiSet.html
+
+use crate::ByteSet;
+use core::fmt;
+
+// These modules must appear in this order to make documentation easier to read.
+// The space between ensures rustfmt does not reorder them.
+mod search;
+pub use search::Split;
+
+mod traits;
+
+/// A set of ASCII bytes: U+0000 NULL ..= U+007F DELETE.
+///
+/// Unlike [`ByteSet`], this can only contain ASCII by construction. It is
+/// half the size, and its `str` operations such as [`trim_matches`] and
+/// [`split`] never fail, because ASCII bytes never appear within multi-byte
+/// UTF-8 sequences and so can never split a `char`.
+///
+/// Converting to a [`ByteSet`] is lossless with [`From`], and converting from
+/// one with [`TryFrom`] fails if it contains any non-ASCII byte.
+///
+/// # Implementation
+///
+/// This is a single `u128` where bit `n` is set if byte `n` is in the set.
+///
+/// # Examples
+///
+/// ```
+/// use byte_set::AsciiSet;
+///
+/// let set = AsciiSet::from_chars("-_ ".chars()).unwrap();
+///
+/// assert!(set.contains_char('-'));
+/// assert!(!set.contains_char('é'));
+/// assert_eq!(set.trim_matches("__héllo wörld-"), "héllo wörld");
+///
+/// // Non-ASCII characters cannot be in the set.
+/// assert_eq!(AsciiSet::from_chars("aé".chars()), None);
+/// ```
+///
+/// [`ByteSet`]: ../struct.ByteSet.html
+/// [`trim_matches`]: #method.trim_matches
+/// [`split`]: #method.split
+/// [`From`]: https://doc.rust-lang.org/std/convert/trait.From.html
+/// [`TryFrom`]: https://doc.rust-lang.org/std/convert/trait.TryFrom.html
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AsciiSet(u128);
+
+impl AsciiSet {
+    /// Returns the set of ASCII bytes in `set`.
+    const fn from_byte_set_lossy(set: ByteSet) -> Self {
+        Self(set.to_u128_array()[0])
+    }
+
+    /// The set of all ASCII alphabetic characters, like
+    /// [`ByteSet::ASCII_ALPHABETIC`](../struct.ByteSet.html#associatedconstant.ASCII_ALPHABETIC).
+    pub const ASCII_ALPHABETIC: Self =
+        Self::from_byte_set_lossy(ByteSet::ASCII_ALPHABETIC);
+
+    /// The set of all ASCII uppercase characters, like
+    /// [`ByteSet::ASCII_UPPERCASE`](../struct.ByteSet.html#associatedconstant.ASCII_UPPERCASE).
+    pub const ASCII_UPPERCASE: Self =
+        Self::from_byte_set_lossy(ByteSet::ASCII_UPPERCASE);
+
+    /// The set of all ASCII lowercase characters, like
+    /// [`ByteSet::ASCII_LOWERCASE`](../struct.ByteSet.html#associatedconstant.ASCII_LOWERCASE).
+    pub const ASCII_LOWERCASE: Self =
+        Self::from_byte_set_lossy(ByteSet::ASCII_LOWERCASE);
+
+    /// The set of all ASCII alphanumeric characters, like
+    /// [`ByteSet::ASCII_ALPHANUMERIC`](../struct.ByteSet.html#associatedconstant.ASCII_ALPHANUMERIC).
+    pub const ASCII_ALPHANUMERIC: Self =
+        Self::from_byte_set_lossy(ByteSet::ASCII_ALPHANUMERIC);
+
+    /// The set of all ASCII decimal digits, like
+    /// [`ByteSet::ASCII_DIGIT`](../struct.ByteSet.html#associatedconstant.ASCII_DIGIT).
+    pub const ASCII_DIGIT: Self =
+        Self::from_byte_set_lossy(ByteSet::ASCII_DIGIT);
+
+    /// The set of all ASCII hexadecimal digits, like
+    /// [`ByteSet::ASCII_HEXDIGIT`](../struct.ByteSet.html#associatedconstant.ASCII_HEXDIGIT).
+    pub const ASCII_HEXDIGIT: Self =
+        Self::from_byte_set_lossy(ByteSet::ASCII_HEXDIGIT);
+
+    /// The set of all ASCII punctuation characters, like
+    /// [`ByteSet::ASCII_PUNCTUATION`](../struct.ByteSet.html#associatedconstant.ASCII_PUNCTUATION).
+    pub const ASCII_PUNCTUATION: Self =
+        Self::from_byte_set_lossy(ByteSet::ASCII_PUNCTUATION);
+
+    /// The set of all ASCII graphic characters, like
+    /// [`ByteSet::ASCII_GRAPHIC`](../struct.ByteSet.html#associatedconstant.ASCII_GRAPHIC).
+    pub const ASCII_GRAPHIC: Self =
+        Self::from_byte_set_lossy(ByteSet::ASCII_GRAPHIC);
+
+    /// The set of all ASCII whitespace characters, like
+    /// [`ByteSet::ASCII_WHITESPACE`](../struct.ByteSet.html#associatedconstant.ASCII_WHITESPACE).
+    pub const ASCII_WHITESPACE: Self =
+        Self::from_byte_set_lossy(ByteSet::ASCII_WHITESPACE);
+
+    /// The set of all ASCII control characters, like
+    /// [`ByteSet::ASCII_CONTROL`](../struct.ByteSet.html#associatedconstant.ASCII_CONTROL).
+    pub const ASCII_CONTROL: Self =
+        Self::from_byte_set_lossy(ByteSet::ASCII_CONTROL);
+
+    /// Returns a set containing no bytes.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Returns a set containing all ASCII bytes.
+    #[inline]
+    #[must_use]
+    pub const fn full() -> Self {
+        Self(!0)
+    }
+
+    /// Returns a set containing the characters in `chars`, or `None` if any of
+    /// them is not ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::AsciiSet;
+    /// let set = AsciiSet::from_chars("0123456789".chars());
+    /// assert_eq!(set, Some(AsciiSet::ASCII_DIGIT));
+    ///
+    /// assert_eq!(AsciiSet::from_chars("π".chars()), None);
+    /// ```
+    #[must_use]
+    pub fn from_chars<I>(chars: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = char>,
+    {
+        let mut set = Self::new();
+        for c in chars {
+            if !c.is_ascii() {
+                return None;
+            }
+            set.insert(c as u8);
+        }
+        Some(set)
+    }
+
+    /// Returns `self` as a [`ByteSet`] containing the same bytes.
+    ///
+    /// [`ByteSet`]: ../struct.ByteSet.html
+    #[inline]
+    #[must_use]
+    pub const fn to_byte_set(self) -> ByteSet {
+        ByteSet::from_u128_array([self.0, 0])
+    }
+
+    /// Returns the number of bytes contained in `self`.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns `true` if `self` contains no bytes.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if `self` contains all ASCII bytes.
+    #[inline]
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.0 == !0
+    }
+
+    /// Returns `true` if `byte` is contained in `self`.
+    ///
+    /// This is always `false` for non-ASCII bytes.
+    #[inline]
+    #[must_use]
+    pub const fn contains(&self, byte: u8) -> bool {
+        byte < 128 && self.0 & (1 << byte) != 0
+    }
+
+    /// Returns `true` if `c` is contained in `self`.
+    ///
+    /// This is always `false` for non-ASCII characters.
+    #[inline]
+    #[must_use]
+    pub const fn contains_char(&self, c: char) -> bool {
+        (c as u32) < 128 && self.contains(c as u8)
+    }
+
+    /// Inserts `byte` into `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `byte` is not ASCII.
+    #[inline]
+    pub fn insert(&mut self, byte: u8) {
+        assert!(byte < 128, "{:#04X} is not an ASCII byte", byte);
+        self.0 |= 1 << byte;
+    }
+
+    /// Removes `byte` from `self`.
+    ///
+    /// This does nothing for non-ASCII bytes.
+    #[inline]
+    pub fn remove(&mut self, byte: u8) {
+        if byte < 128 {
+            self.0 &= !(1 << byte);
+        }
+    }
+
+    /// Returns a set with the bytes contained in `self`, but not in `other`.
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Returns a set with the bytes contained in `self` or `other`, but not in
+    /// both.
+    #[inline]
+    #[must_use]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    /// Returns a set with the bytes contained in both `self` and `other`.
+    #[inline]
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Returns a set with the bytes contained in `self`, `other`, or in both.
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns the ASCII bytes not contained in `self`.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::should_implement_trait)]
+    pub const fn not(self) -> Self {
+        Self(!self.0)
+    }
+
+    /// Returns `true` if every byte in `self` is also in `other`.
+    #[inline]
+    #[must_use]
+    pub const fn is_subset(&self, other: &Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// Returns `true` if every byte in `other` is also in `self`.
+    #[inline]
+    #[must_use]
+    pub const fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns `true` if `self` and `other` have no bytes in common.
+    #[inline]
+    #[must_use]
+    pub const fn is_disjoint(&self, other: &Self) -> bool {
+        self.0 & other.0 == 0
+    }
+}
+
+/// The error returned when converting a [`ByteSet`] that contains non-ASCII
+/// bytes into an [`AsciiSet`].
+///
+/// [`ByteSet`]: ../struct.ByteSet.html
+/// [`AsciiSet`]: struct.AsciiSet.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NonAsciiError {
+    non_ascii: ByteSet,
+}
+
+impl NonAsciiError {
+    /// Returns the non-ASCII bytes of the set that failed to convert.
+    #[inline]
+    pub const fn non_ascii(&self) -> ByteSet {
+        self.non_ascii
+    }
+}
+
+impl fmt::Display for NonAsciiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "set contains {} non-ASCII bytes, starting at {:#04X}",
+            self.non_ascii.len(),
+            self.non_ascii.first().unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(any(test, feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for NonAsciiError {}
@@ -0,0 +1,129 @@
+use super::AsciiSet;
+use core::{iter, str};
+
+/// Operations on `str` that are sound because every byte in the set is ASCII.
+///
+/// ASCII bytes never appear within multi-byte UTF-8 sequences, so any index
+/// of a byte in the set is a `char` boundary.
+impl AsciiSet {
+    /// Returns the index of the first character in `s` that is contained in
+    /// `self`, or `None` if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::AsciiSet;
+    /// let set = AsciiSet::ASCII_DIGIT;
+    ///
+    /// assert_eq!(set.find("née en 1984"), Some(8));
+    /// assert_eq!(set.find("née"), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn find(&self, s: &str) -> Option<usize> {
+        self.to_byte_set().find_in(s.as_bytes())
+    }
+
+    /// Returns the index of the last character in `s` that is contained in
+    /// `self`, or `None` if there is none.
+    #[inline]
+    #[must_use]
+    pub fn rfind(&self, s: &str) -> Option<usize> {
+        self.to_byte_set().rfind_in(s.as_bytes())
+    }
+
+    /// Returns `s` with all leading and trailing characters contained in
+    /// `self` removed.
+    ///
+    /// This is like [`str::trim_matches`] with a set of characters.
+    ///
+    /// [`str::trim_matches`]: https://doc.rust-lang.org/std/primitive.str.html#method.trim_matches
+    #[inline]
+    #[must_use]
+    pub fn trim_matches<'h>(&self, s: &'h str) -> &'h str {
+        // SAFETY: Only ASCII bytes were removed from the ends of valid UTF-8.
+        unsafe {
+            str::from_utf8_unchecked(self.to_byte_set().trim(s.as_bytes()))
+        }
+    }
+
+    /// Returns `s` with all leading characters contained in `self` removed.
+    #[inline]
+    #[must_use]
+    pub fn trim_start_matches<'h>(&self, s: &'h str) -> &'h str {
+        let bytes = self.to_byte_set().trim_start(s.as_bytes());
+        // SAFETY: Only ASCII bytes were removed from the start of valid UTF-8.
+        unsafe { str::from_utf8_unchecked(bytes) }
+    }
+
+    /// Returns `s` with all trailing characters contained in `self` removed.
+    #[inline]
+    #[must_use]
+    pub fn trim_end_matches<'h>(&self, s: &'h str) -> &'h str {
+        let bytes = self.to_byte_set().trim_end(s.as_bytes());
+        // SAFETY: Only ASCII bytes were removed from the end of valid UTF-8.
+        unsafe { str::from_utf8_unchecked(bytes) }
+    }
+
+    /// Returns an iterator over substrings of `s` separated by characters
+    /// contained in `self`.
+    ///
+    /// This is like [`str::split`] with a set of characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::AsciiSet;
+    /// let set = AsciiSet::from_chars(",;".chars()).unwrap();
+    /// let parts: Vec<&str> = set.split("α,β;;γ").collect();
+    ///
+    /// assert_eq!(parts, ["α", "β", "", "γ"]);
+    /// ```
+    ///
+    /// [`str::split`]: https://doc.rust-lang.org/std/primitive.str.html#method.split
+    #[inline]
+    pub fn split<'h>(&self, s: &'h str) -> Split<'h> {
+        Split {
+            inner: self.to_byte_set().split(s.as_bytes()),
+        }
+    }
+}
+
+/// An iterator over substrings separated by characters in an [`AsciiSet`].
+///
+/// This is created by [`AsciiSet::split`].
+///
+/// [`AsciiSet`]: struct.AsciiSet.html
+/// [`AsciiSet::split`]: struct.AsciiSet.html#method.split
+#[derive(Clone, Debug)]
+pub struct Split<'h> {
+    /// Splits the bytes of a `str` at ASCII bytes only.
+    inner: crate::Split<'h>,
+}
+
+impl<'h> Iterator for Split<'h> {
+    type Item = &'h str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'h str> {
+        let part = self.inner.next()?;
+        // SAFETY: Valid UTF-8 split at ASCII bytes is valid UTF-8.
+        Some(unsafe { str::from_utf8_unchecked(part) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'h> DoubleEndedIterator for Split<'h> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'h str> {
+        let part = self.inner.next_back()?;
+        // SAFETY: Valid UTF-8 split at ASCII bytes is valid UTF-8.
+        Some(unsafe { str::from_utf8_unchecked(part) })
+    }
+}
+
+impl iter::FusedIterator for Split<'_> {}
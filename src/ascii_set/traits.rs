@@ -0,0 +1,96 @@
+use super::{AsciiSet, NonAsciiError};
+use crate::ByteSet;
+use core::{cmp, convert::TryFrom, fmt, ops};
+
+impl fmt::Debug for AsciiSet {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_byte_set().fmt(f)
+    }
+}
+
+impl PartialOrd for AsciiSet {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AsciiSet {
+    /// Compares sets by the lexicographic order of their bytes, like
+    /// [`ByteSet`](../struct.ByteSet.html).
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.to_byte_set().cmp(&other.to_byte_set())
+    }
+}
+
+impl From<AsciiSet> for ByteSet {
+    #[inline]
+    fn from(set: AsciiSet) -> Self {
+        set.to_byte_set()
+    }
+}
+
+impl TryFrom<ByteSet> for AsciiSet {
+    type Error = NonAsciiError;
+
+    /// Converts `set` if all of its bytes are ASCII.
+    #[inline]
+    fn try_from(set: ByteSet) -> Result<Self, NonAsciiError> {
+        let non_ascii = set.difference(ByteSet::ASCII);
+        if non_ascii.is_empty() {
+            Ok(Self::from_byte_set_lossy(set))
+        } else {
+            Err(NonAsciiError { non_ascii })
+        }
+    }
+}
+
+impl IntoIterator for AsciiSet {
+    type Item = u8;
+    type IntoIter = crate::Iter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_byte_set().into_iter()
+    }
+}
+
+impl ops::Not for AsciiSet {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        AsciiSet::not(self)
+    }
+}
+
+macro_rules! impl_op {
+    ($($op:ident, $f:ident, $assign:ident, $assign_f:ident => $method:ident;)+) => {
+        $(
+            impl ops::$op for AsciiSet {
+                type Output = Self;
+
+                #[inline]
+                fn $f(self, rhs: Self) -> Self {
+                    self.$method(rhs)
+                }
+            }
+
+            impl ops::$assign for AsciiSet {
+                #[inline]
+                fn $assign_f(&mut self, rhs: Self) {
+                    *self = self.$method(rhs);
+                }
+            }
+        )+
+    };
+}
+
+impl_op! {
+    BitAnd, bitand, BitAndAssign, bitand_assign => intersection;
+    BitOr, bitor, BitOrAssign, bitor_assign => union;
+    BitXor, bitxor, BitXorAssign, bitxor_assign => symmetric_difference;
+    Sub, sub, SubAssign, sub_assign => difference;
+}
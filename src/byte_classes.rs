@@ -0,0 +1,200 @@
+use crate::ByteSet;
+use core::fmt;
+
+/// A partition of all bytes into equivalence classes, such that bytes in the
+/// same class are treated the same by every set it was built from.
+///
+/// This is the coarsest such partition, so it has as few classes as possible.
+/// Lexers and DFAs can use it to index transition tables by class instead of
+/// by byte, which makes them much smaller.
+///
+/// Classes are numbered from `0` in order of their least byte, so the class of
+/// `0` is always `0`.
+///
+/// # Implementation
+///
+/// Classes are built by partition refinement. Starting from a single class of
+/// all bytes, each set splits every class it partially overlaps into the
+/// [`intersection`] and [`difference`] with the set. The class of each byte is
+/// then stored in a 256-entry table, so [`class_of`] is a single lookup.
+///
+/// # Examples
+///
+/// ```
+/// use byte_set::{ByteClasses, ByteSet};
+///
+/// let classes = ByteClasses::from_sets(vec![
+///     ByteSet::ASCII_DIGIT,
+///     ByteSet::ASCII_ALPHANUMERIC,
+/// ]);
+///
+/// // Digits, other alphanumerics, and everything else.
+/// assert_eq!(classes.num_classes(), 3);
+/// assert_eq!(classes.class_of(b'1'), classes.class_of(b'9'));
+/// assert_eq!(classes.class_of(b'a'), classes.class_of(b'Z'));
+/// assert_ne!(classes.class_of(b'a'), classes.class_of(b'9'));
+/// assert_eq!(classes.members(classes.class_of(b'0')), ByteSet::ASCII_DIGIT);
+/// ```
+///
+/// [`intersection`]: struct.ByteSet.html#method.intersection
+/// [`difference`]: struct.ByteSet.html#method.difference
+/// [`class_of`]: #method.class_of
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ByteClasses {
+    /// The class of each byte.
+    classes: [u8; 256],
+    /// The least byte of each class.
+    representatives: ByteSet,
+}
+
+impl ByteClasses {
+    /// Returns a partition with a single class of all bytes.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            classes: [0; 256],
+            representatives: ByteSet::from_byte(0),
+        }
+    }
+
+    /// Returns the coarsest partition of all bytes such that every set in
+    /// `sets` either contains all bytes of a class or none of them.
+    ///
+    /// Empty and full sets do not split any class, so they can be included
+    /// freely.
+    pub fn from_sets<I>(sets: I) -> Self
+    where
+        I: IntoIterator<Item = ByteSet>,
+    {
+        let mut parts = [ByteSet::new(); 256];
+        parts[0] = ByteSet::full();
+        let mut len = 1;
+
+        for set in sets {
+            // Only split the parts that existed before this set, since new
+            // parts are already inside or outside of it.
+            let num_parts = len;
+            for i in 0..num_parts {
+                let part = parts[i];
+
+                let inside = part.intersection(set);
+                if inside.is_empty() || inside == part {
+                    continue;
+                }
+
+                parts[i] = inside;
+                parts[len] = part.difference(set);
+                len += 1;
+            }
+
+            // Every byte is in its own class, so nothing can be split further.
+            if len == 256 {
+                break;
+            }
+        }
+
+        let mut representatives = ByteSet::new();
+        for part in &parts[..len] {
+            representatives.insert(part.first().unwrap_or_default());
+        }
+
+        // Number the parts by the rank of their least byte.
+        let mut classes = [0; 256];
+        for part in &parts[..len] {
+            let first = part.first().unwrap_or_default();
+            let class = representatives.rank(first) as u8;
+
+            for byte in *part {
+                classes[byte as usize] = class;
+            }
+        }
+
+        Self {
+            classes,
+            representatives,
+        }
+    }
+
+    /// Returns the number of classes, from 1 to 256.
+    #[inline]
+    #[must_use]
+    pub const fn num_classes(&self) -> usize {
+        self.representatives.len()
+    }
+
+    /// Returns the class of `byte`.
+    #[inline]
+    #[must_use]
+    pub const fn class_of(&self, byte: u8) -> u8 {
+        self.classes[byte as usize]
+    }
+
+    /// Returns the bytes in `class`, which is empty if `class` is not less than
+    /// [`num_classes`](#method.num_classes).
+    #[must_use]
+    pub fn members(&self, class: u8) -> ByteSet {
+        let mut set = ByteSet::new();
+        for (byte, &byte_class) in self.classes.iter().enumerate() {
+            set.set(byte as u8, byte_class == class);
+        }
+        set
+    }
+
+    /// Returns the least byte of `class`, or `None` if `class` is not less than
+    /// [`num_classes`](#method.num_classes).
+    ///
+    /// Any byte of a class can stand in for the others, such as when building
+    /// a transition table by class.
+    #[inline]
+    #[must_use]
+    pub const fn representative(&self, class: u8) -> Option<u8> {
+        self.representatives.select(class as usize)
+    }
+
+    /// Returns the set of the least byte of each class.
+    ///
+    /// Iterating over this set yields one byte per class, in order of class.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::{ByteClasses, ByteSet};
+    /// let classes = ByteClasses::from_sets(vec![
+    ///     ByteSet::ASCII_DIGIT,
+    ///     ByteSet::ASCII_LOWERCASE,
+    /// ]);
+    ///
+    /// let bytes: Vec<u8> = classes.representatives().into_iter().collect();
+    /// assert_eq!(bytes, [0, b'0', b'a']);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn representatives(&self) -> ByteSet {
+        self.representatives
+    }
+
+    /// Returns the class of each byte, indexed by byte.
+    #[inline]
+    #[must_use]
+    pub const fn as_array(&self) -> &[u8; 256] {
+        &self.classes
+    }
+}
+
+impl Default for ByteClasses {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ByteClasses {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let classes = self.representatives.into_iter().map(|byte| {
+            let class = self.class_of(byte);
+            self.members(class)
+        });
+        f.debug_list().entries(classes).finish()
+    }
+}
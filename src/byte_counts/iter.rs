@@ -0,0 +1,57 @@
+use super::ByteCounts;
+use core::iter;
+
+/// An iterator over the bytes with a nonzero count in a [`ByteCounts`] and
+/// their counts, ordered by byte.
+///
+/// This is created by [`ByteCounts::iter`].
+///
+/// [`ByteCounts`]: struct.ByteCounts.html
+/// [`ByteCounts::iter`]: struct.ByteCounts.html#method.iter
+#[derive(Clone)]
+pub struct Iter<'a> {
+    bytes: crate::Iter,
+    counts: &'a [u64; 256],
+}
+
+impl<'a> Iter<'a> {
+    #[inline]
+    pub(super) fn new(counts: &'a ByteCounts) -> Self {
+        Self {
+            bytes: counts.support().into_iter(),
+            counts: counts.as_array(),
+        }
+    }
+}
+
+impl Iterator for Iter<'_> {
+    type Item = (u8, u64);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let byte = self.bytes.next()?;
+        Some((byte, self.counts[byte as usize]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.bytes.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let byte = self.bytes.next_back()?;
+        Some((byte, self.counts[byte as usize]))
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.bytes.len()
+    }
+}
+
+impl iter::FusedIterator for Iter<'_> {}
@@ -0,0 +1,287 @@
+//! A histogram of [`u8`] occurrences that interoperates with [`ByteSet`].
+//!
+//! See [`ByteCounts`] for more info.
+//!
+//! [`u8`]: https://doc.rust-lang.org/std/primitive.u8.html
+//! [`ByteSet`]: ../struct.ByteSet.html
+//! [`ByteCounts`]: struct.ByteCounts.html
+
+use crate::ByteSet;
+
+// These modules must appear in this order to make documentation easier to read.
+// The space between ensures rustfmt does not reorder them.
+mod iter;
+pub use iter::Iter;
+
+mod traits;
+
+/// A count of occurrences for each [`u8`], the multiset counterpart of
+/// [`ByteSet`].
+///
+/// Queries such as [`support`] and [`above`] return ordinary [`ByteSet`]s, so
+/// they can be combined with every set operation. This makes it easy to pick a
+/// rare byte to search for first, or to check which bytes an encoding must
+/// handle.
+///
+/// # Implementation
+///
+/// Counts are stored inline as `[u64; 256]`, indexed by byte. Counting a slice
+/// spreads consecutive bytes across several tables that are summed at the end,
+/// the same way [`ByteSet::from_slice`] spreads them across partial sets.
+///
+/// # Examples
+///
+/// ```
+/// use byte_set::{ByteCounts, ByteSet};
+///
+/// let counts = ByteCounts::from_slice(b"abracadabra");
+///
+/// assert_eq!(counts.count(b'a'), 5);
+/// assert_eq!(counts.support(), ByteSet::from(&b"abcdr"[..]));
+/// assert_eq!(counts.above(1), ByteSet::from(&b"abr"[..]));
+/// assert_eq!(counts.rarest_in(ByteSet::from(&b"abr"[..])), Some(b'b'));
+/// ```
+///
+/// [`u8`]: https://doc.rust-lang.org/std/primitive.u8.html
+/// [`ByteSet`]: ../struct.ByteSet.html
+/// [`ByteSet::from_slice`]: ../struct.ByteSet.html#method.from_slice
+/// [`support`]: #method.support
+/// [`above`]: #method.above
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ByteCounts {
+    counts: [u64; 256],
+}
+
+impl ByteCounts {
+    /// The number of tables that consecutive bytes are spread across.
+    ///
+    /// See `Accumulator` in `byte_set/accumulate.rs` for why this is faster.
+    const NUM_TABLES: usize = 4;
+
+    /// Slices shorter than this are counted directly into `self`, since the
+    /// extra tables are not worth zeroing and summing.
+    const MIN_TABLES_LEN: usize = 1024;
+
+    /// Returns counts of zero for every byte.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { counts: [0; 256] }
+    }
+
+    /// Returns the counts of each byte in `bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteCounts;
+    /// let counts = ByteCounts::from_slice(b"hello");
+    ///
+    /// assert_eq!(counts.count(b'l'), 2);
+    /// assert_eq!(counts.total(), 5);
+    /// ```
+    #[must_use]
+    pub fn from_slice(bytes: &[u8]) -> Self {
+        let mut counts = Self::new();
+        counts.add_slice(bytes);
+        counts
+    }
+
+    /// Returns a reference to the underlying counts, indexed by byte.
+    #[inline]
+    #[must_use]
+    pub const fn as_array(&self) -> &[u64; 256] {
+        &self.counts
+    }
+
+    /// Returns the count of `byte`.
+    #[inline]
+    #[must_use]
+    pub const fn count(&self, byte: u8) -> u64 {
+        self.counts[byte as usize]
+    }
+
+    /// Sets the count of `byte` to `count`.
+    #[inline]
+    pub fn set_count(&mut self, byte: u8, count: u64) {
+        self.counts[byte as usize] = count;
+    }
+
+    /// Returns the sum of all counts.
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Returns `true` if every count is zero.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.counts.iter().all(|&count| count == 0)
+    }
+
+    /// Sets every count to zero.
+    #[inline]
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Increments the count of `byte` by one.
+    #[inline]
+    pub fn insert(&mut self, byte: u8) {
+        self.counts[byte as usize] += 1;
+    }
+
+    /// Increments the count of each byte in `bytes`.
+    pub fn add_slice(&mut self, bytes: &[u8]) {
+        if bytes.len() < Self::MIN_TABLES_LEN {
+            bytes.iter().for_each(|&byte| self.insert(byte));
+            return;
+        }
+
+        let mut tables = [[0u64; 256]; Self::NUM_TABLES];
+
+        let mut groups = bytes.chunks_exact(Self::NUM_TABLES);
+        for group in &mut groups {
+            for (table, &byte) in tables.iter_mut().zip(group) {
+                table[byte as usize] += 1;
+            }
+        }
+        for &byte in groups.remainder() {
+            tables[0][byte as usize] += 1;
+        }
+
+        for (i, count) in self.counts.iter_mut().enumerate() {
+            *count += tables.iter().map(|table| table[i]).sum::<u64>();
+        }
+    }
+
+    /// Adds the counts of `other` to `self`.
+    pub fn merge(&mut self, other: &Self) {
+        for (count, &other) in self.counts.iter_mut().zip(&other.counts) {
+            *count += other;
+        }
+    }
+
+    /// Subtracts the counts of `other` from `self`, stopping at zero.
+    pub fn subtract(&mut self, other: &Self) {
+        for (count, &other) in self.counts.iter_mut().zip(&other.counts) {
+            *count = count.saturating_sub(other);
+        }
+    }
+
+    /// Returns the set of bytes with a nonzero count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::{ByteCounts, ByteSet};
+    /// let counts = ByteCounts::from_slice(b"mississippi");
+    ///
+    /// assert_eq!(counts.support(), ByteSet::from(&b"imps"[..]));
+    /// ```
+    #[must_use]
+    pub fn support(&self) -> ByteSet {
+        self.above(0)
+    }
+
+    /// Returns the set of bytes with a count greater than `threshold`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::{ByteCounts, ByteSet};
+    /// let counts = ByteCounts::from_slice(b"mississippi");
+    ///
+    /// assert_eq!(counts.above(2), ByteSet::from(&b"is"[..]));
+    /// assert_eq!(counts.above(4), ByteSet::new());
+    /// ```
+    #[must_use]
+    pub fn above(&self, threshold: u64) -> ByteSet {
+        let mut set = ByteSet::new();
+        for (byte, &count) in self.counts.iter().enumerate() {
+            set.set(byte as u8, count > threshold);
+        }
+        set
+    }
+
+    /// Returns the set of the `k` bytes with the greatest counts.
+    ///
+    /// Ties are broken in favor of lesser bytes. Bytes with a count of zero are
+    /// never included, so this has fewer than `k` bytes if fewer than `k` bytes
+    /// occur.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::{ByteCounts, ByteSet};
+    /// let counts = ByteCounts::from_slice(b"mississippi");
+    ///
+    /// assert_eq!(counts.top_k(1), ByteSet::from(&b"i"[..]));
+    /// assert_eq!(counts.top_k(3), ByteSet::from(&b"ips"[..]));
+    /// assert_eq!(counts.top_k(10), counts.support());
+    /// ```
+    #[must_use]
+    pub fn top_k(&self, k: usize) -> ByteSet {
+        let mut bytes = [0u8; 256];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+
+        let k = k.min(256);
+        let by_count_desc = |&a: &u8, &b: &u8| {
+            self.count(b).cmp(&self.count(a)).then(a.cmp(&b))
+        };
+        bytes.sort_unstable_by(by_count_desc);
+
+        let mut set = ByteSet::new();
+        for &byte in &bytes[..k] {
+            if self.count(byte) == 0 {
+                break;
+            }
+            set.insert(byte);
+        }
+        set
+    }
+
+    /// Returns the byte in `set` with the least count, or `None` if `set` is
+    /// empty.
+    ///
+    /// Ties are broken in favor of lesser bytes. Bytes with a count of zero are
+    /// included, since they are the rarest of all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::{ByteCounts, ByteSet};
+    /// let counts = ByteCounts::from_slice(b"the quick brown fox");
+    ///
+    /// assert_eq!(counts.rarest_in(ByteSet::from(&b"o "[..])), Some(b'o'));
+    /// assert_eq!(counts.rarest_in(ByteSet::ASCII_DIGIT), Some(b'0'));
+    /// assert_eq!(counts.rarest_in(ByteSet::new()), None);
+    /// ```
+    #[must_use]
+    pub fn rarest_in(&self, set: ByteSet) -> Option<u8> {
+        // `min_by_key` returns the first minimum, which is the least byte.
+        set.into_iter().min_by_key(|&byte| self.count(byte))
+    }
+
+    /// Returns the byte with the greatest count, or `None` if every count is
+    /// zero.
+    ///
+    /// Ties are broken in favor of lesser bytes.
+    #[must_use]
+    pub fn most_common(&self) -> Option<u8> {
+        self.iter()
+            .max_by(|(a, a_count), (b, b_count)| {
+                a_count.cmp(b_count).then(b.cmp(a))
+            })
+            .map(|(byte, _)| byte)
+    }
+
+    /// Returns an iterator over the bytes with a nonzero count and their
+    /// counts, ordered by byte.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_> {
+        Iter::new(self)
+    }
+}
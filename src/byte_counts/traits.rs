@@ -0,0 +1,140 @@
+use super::{ByteCounts, Iter};
+use core::{fmt, iter::FromIterator, ops};
+
+impl Default for ByteCounts {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ByteCounts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl ops::Index<u8> for ByteCounts {
+    type Output = u64;
+
+    /// Returns a reference to the count of `byte`.
+    #[inline]
+    fn index(&self, byte: u8) -> &u64 {
+        &self.as_array()[byte as usize]
+    }
+}
+
+impl From<&[u8]> for ByteCounts {
+    #[inline]
+    fn from(bytes: &[u8]) -> Self {
+        Self::from_slice(bytes)
+    }
+}
+
+impl Extend<u8> for ByteCounts {
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|byte| self.insert(byte));
+    }
+}
+
+impl<'a> Extend<&'a u8> for ByteCounts {
+    fn extend<T: IntoIterator<Item = &'a u8>>(&mut self, iter: T) {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+impl FromIterator<u8> for ByteCounts {
+    fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
+        let mut counts = Self::new();
+        counts.extend(iter);
+        counts
+    }
+}
+
+impl<'a> FromIterator<&'a u8> for ByteCounts {
+    fn from_iter<T: IntoIterator<Item = &'a u8>>(iter: T) -> Self {
+        iter.into_iter().cloned().collect()
+    }
+}
+
+impl<'a> IntoIterator for &'a ByteCounts {
+    type Item = (u8, u64);
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Adds counts, like [`merge`](struct.ByteCounts.html#method.merge).
+impl ops::AddAssign<&ByteCounts> for ByteCounts {
+    #[inline]
+    fn add_assign(&mut self, rhs: &ByteCounts) {
+        self.merge(rhs);
+    }
+}
+
+impl ops::AddAssign for ByteCounts {
+    #[inline]
+    fn add_assign(&mut self, rhs: ByteCounts) {
+        self.merge(&rhs);
+    }
+}
+
+/// Adds counts, like [`merge`](struct.ByteCounts.html#method.merge).
+impl ops::Add<&ByteCounts> for ByteCounts {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: &ByteCounts) -> Self {
+        self.merge(rhs);
+        self
+    }
+}
+
+impl ops::Add for ByteCounts {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: ByteCounts) -> Self {
+        self + &rhs
+    }
+}
+
+/// Subtracts counts, stopping at zero, like
+/// [`subtract`](struct.ByteCounts.html#method.subtract).
+impl ops::SubAssign<&ByteCounts> for ByteCounts {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &ByteCounts) {
+        self.subtract(rhs);
+    }
+}
+
+impl ops::SubAssign for ByteCounts {
+    #[inline]
+    fn sub_assign(&mut self, rhs: ByteCounts) {
+        self.subtract(&rhs);
+    }
+}
+
+/// Subtracts counts, stopping at zero, like
+/// [`subtract`](struct.ByteCounts.html#method.subtract).
+impl ops::Sub<&ByteCounts> for ByteCounts {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: &ByteCounts) -> Self {
+        self.subtract(rhs);
+        self
+    }
+}
+
+impl ops::Sub for ByteCounts {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: ByteCounts) -> Self {
+        self - &rhs
+    }
+}
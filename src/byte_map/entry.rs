@@ -0,0 +1,164 @@
+use super::ByteMap;
+use core::{fmt, mem};
+
+/// A view into a single entry of a [`ByteMap`], which may be occupied or
+/// vacant.
+///
+/// This is created by [`ByteMap::entry`].
+///
+/// [`ByteMap`]: struct.ByteMap.html
+/// [`ByteMap::entry`]: struct.ByteMap.html#method.entry
+#[derive(Debug)]
+pub enum Entry<'a, V> {
+    /// An entry with a value.
+    Occupied(OccupiedEntry<'a, V>),
+    /// An entry without a value.
+    Vacant(VacantEntry<'a, V>),
+}
+
+/// A view into an occupied entry of a [`ByteMap`].
+///
+/// [`ByteMap`]: struct.ByteMap.html
+pub struct OccupiedEntry<'a, V> {
+    map: &'a mut ByteMap<V>,
+    key: u8,
+}
+
+/// A view into a vacant entry of a [`ByteMap`].
+///
+/// [`ByteMap`]: struct.ByteMap.html
+pub struct VacantEntry<'a, V> {
+    map: &'a mut ByteMap<V>,
+    key: u8,
+}
+
+impl<'a, V> Entry<'a, V> {
+    /// Returns the key of this entry.
+    #[inline]
+    pub fn key(&self) -> u8 {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Inserts `default` if the entry is vacant, and returns a mutable
+    /// reference to the value.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Inserts the result of `default` if the entry is vacant, and returns a
+    /// mutable reference to the value.
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Calls `f` with the value if the entry is occupied.
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, V: Default> Entry<'a, V> {
+    /// Inserts the default value if the entry is vacant, and returns a mutable
+    /// reference to the value.
+    #[inline]
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert_with(V::default)
+    }
+}
+
+impl<'a, V> OccupiedEntry<'a, V> {
+    #[inline]
+    pub(super) fn new(map: &'a mut ByteMap<V>, key: u8) -> Self {
+        debug_assert!(map.contains_key(key));
+        Self { map, key }
+    }
+
+    /// Returns the key of this entry.
+    #[inline]
+    pub fn key(&self) -> u8 {
+        self.key
+    }
+
+    /// Returns a reference to the value of this entry.
+    #[inline]
+    pub fn get(&self) -> &V {
+        unsafe { self.map.get_unchecked(self.key) }
+    }
+
+    /// Returns a mutable reference to the value of this entry.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut V {
+        unsafe { self.map.get_unchecked_mut(self.key) }
+    }
+
+    /// Returns a mutable reference to the value of this entry with the
+    /// lifetime of the map.
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V {
+        unsafe { self.map.get_unchecked_mut(self.key) }
+    }
+
+    /// Replaces the value of this entry with `value` and returns the old value.
+    #[inline]
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes this entry from the map and returns its value.
+    #[inline]
+    pub fn remove(self) -> V {
+        unsafe { self.map.remove_unchecked(self.key) }
+    }
+}
+
+impl<'a, V> VacantEntry<'a, V> {
+    #[inline]
+    pub(super) fn new(map: &'a mut ByteMap<V>, key: u8) -> Self {
+        debug_assert!(!map.contains_key(key));
+        Self { map, key }
+    }
+
+    /// Returns the key of this entry.
+    #[inline]
+    pub fn key(&self) -> u8 {
+        self.key
+    }
+
+    /// Inserts `value` into this entry and returns a mutable reference to it.
+    #[inline]
+    pub fn insert(self, value: V) -> &'a mut V {
+        unsafe { self.map.insert_unchecked(self.key, value) }
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for OccupiedEntry<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OccupiedEntry")
+            .field("key", &self.key)
+            .field("value", self.get())
+            .finish()
+    }
+}
+
+impl<V> fmt::Debug for VacantEntry<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("VacantEntry")
+            .field("key", &self.key)
+            .finish()
+    }
+}
@@ -0,0 +1,325 @@
+use super::ByteMap;
+use core::{fmt, iter, marker::PhantomData, mem::MaybeUninit};
+
+/// An iterator over the entries of a [`ByteMap`], ordered by key.
+///
+/// This is created by [`ByteMap::iter`].
+///
+/// [`ByteMap`]: struct.ByteMap.html
+/// [`ByteMap::iter`]: struct.ByteMap.html#method.iter
+pub struct Iter<'a, V> {
+    keys: crate::Iter,
+    map: &'a ByteMap<V>,
+}
+
+/// An iterator over the entries of a [`ByteMap`] with mutable references to
+/// values, ordered by key.
+///
+/// This is created by [`ByteMap::iter_mut`].
+///
+/// [`ByteMap`]: struct.ByteMap.html
+/// [`ByteMap::iter_mut`]: struct.ByteMap.html#method.iter_mut
+pub struct IterMut<'a, V> {
+    keys: crate::Iter,
+    values: *mut MaybeUninit<V>,
+    marker: PhantomData<&'a mut V>,
+}
+
+/// An iterator over the values of a [`ByteMap`], ordered by key.
+///
+/// This is created by [`ByteMap::values`].
+///
+/// [`ByteMap`]: struct.ByteMap.html
+/// [`ByteMap::values`]: struct.ByteMap.html#method.values
+pub struct Values<'a, V> {
+    inner: Iter<'a, V>,
+}
+
+/// An iterator over mutable references to the values of a [`ByteMap`],
+/// ordered by key.
+///
+/// This is created by [`ByteMap::values_mut`].
+///
+/// [`ByteMap`]: struct.ByteMap.html
+/// [`ByteMap::values_mut`]: struct.ByteMap.html#method.values_mut
+pub struct ValuesMut<'a, V> {
+    inner: IterMut<'a, V>,
+}
+
+/// An owning iterator over the entries of a [`ByteMap`], ordered by key.
+///
+/// This is created by calling `into_iter` on a [`ByteMap`].
+///
+/// [`ByteMap`]: struct.ByteMap.html
+pub struct IntoIter<V> {
+    /// Entries are removed from the map as they are iterated over, so that the
+    /// map drops only the remaining values.
+    map: ByteMap<V>,
+}
+
+impl<'a, V> Iter<'a, V> {
+    #[inline]
+    pub(super) fn new(map: &'a ByteMap<V>) -> Self {
+        Self {
+            keys: map.keys.into_iter(),
+            map,
+        }
+    }
+
+    #[inline]
+    fn entry(&self, key: u8) -> (u8, &'a V) {
+        // SAFETY: `keys` is a subset of the map's keys.
+        (key, unsafe { self.map.get_unchecked(key) })
+    }
+}
+
+impl<'a, V> IterMut<'a, V> {
+    #[inline]
+    pub(super) fn new(map: &'a mut ByteMap<V>) -> Self {
+        Self {
+            keys: map.keys.into_iter(),
+            values: map.values.as_mut_ptr(),
+            marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn entry(&mut self, key: u8) -> (u8, &'a mut V) {
+        // SAFETY: `keys` is a subset of the map's keys, and each key is only
+        // yielded once, so references never alias.
+        (key, unsafe {
+            &mut *(*self.values.add(key as usize)).as_mut_ptr()
+        })
+    }
+}
+
+impl<'a, V> Values<'a, V> {
+    #[inline]
+    pub(super) fn new(inner: Iter<'a, V>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'a, V> ValuesMut<'a, V> {
+    #[inline]
+    pub(super) fn new(inner: IterMut<'a, V>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'a, V> Iterator for Iter<'a, V> {
+    type Item = (u8, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.keys.next()?;
+        Some(self.entry(key))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<V> DoubleEndedIterator for Iter<'_, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let key = self.keys.next_back()?;
+        Some(self.entry(key))
+    }
+}
+
+impl<'a, V> Iterator for IterMut<'a, V> {
+    type Item = (u8, &'a mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.keys.next()?;
+        Some(self.entry(key))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<V> DoubleEndedIterator for IterMut<'_, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let key = self.keys.next_back()?;
+        Some(self.entry(key))
+    }
+}
+
+impl<'a, V> Iterator for Values<'a, V> {
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a V> {
+        self.inner.next().map(|(_, value)| value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<V> DoubleEndedIterator for Values<'_, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<'a, V> Iterator for ValuesMut<'a, V> {
+    type Item = &'a mut V;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut V> {
+        self.inner.next().map(|(_, value)| value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<V> DoubleEndedIterator for ValuesMut<'_, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<V> Iterator for IntoIter<V> {
+    type Item = (u8, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(u8, V)> {
+        let key = self.map.keys.first()?;
+        Some((key, unsafe { self.map.remove_unchecked(key) }))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.map.len();
+        (len, Some(len))
+    }
+}
+
+impl<V> DoubleEndedIterator for IntoIter<V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(u8, V)> {
+        let key = self.map.keys.last()?;
+        Some((key, unsafe { self.map.remove_unchecked(key) }))
+    }
+}
+
+impl<V> IntoIterator for ByteMap<V> {
+    type Item = (u8, V);
+    type IntoIter = IntoIter<V>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<V> {
+        IntoIter { map: self }
+    }
+}
+
+impl<'a, V> IntoIterator for &'a ByteMap<V> {
+    type Item = (u8, &'a V);
+    type IntoIter = Iter<'a, V>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, V> {
+        self.iter()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a mut ByteMap<V> {
+    type Item = (u8, &'a mut V);
+    type IntoIter = IterMut<'a, V>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, V> {
+        self.iter_mut()
+    }
+}
+
+// Each iterator has an exact length and does not produce more values after
+// `None` is reached.
+macro_rules! impl_exact_fused {
+    ($($iter:ty,)+) => {
+        $(
+            impl<V> ExactSizeIterator for $iter {}
+            impl<V> iter::FusedIterator for $iter {}
+        )+
+    };
+}
+
+impl_exact_fused! {
+    Iter<'_, V>,
+    IterMut<'_, V>,
+    Values<'_, V>,
+    ValuesMut<'_, V>,
+    IntoIter<V>,
+}
+
+impl<V> Clone for Iter<'_, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            keys: self.keys,
+            map: self.map,
+        }
+    }
+}
+
+impl<V> Clone for Values<'_, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+// SAFETY: `IterMut` behaves like `&mut V` for each value.
+unsafe impl<V: Send> Send for IterMut<'_, V> {}
+unsafe impl<V: Sync> Sync for IterMut<'_, V> {}
+
+impl<V: fmt::Debug> fmt::Debug for Iter<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for Values<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<V> fmt::Debug for IterMut<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Values cannot be borrowed while they may be mutably borrowed.
+        f.debug_struct("IterMut").field("keys", &self.keys).finish()
+    }
+}
+
+impl<V> fmt::Debug for ValuesMut<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ValuesMut")
+            .field("keys", &self.inner.keys)
+            .finish()
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for IntoIter<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.map.iter()).finish()
+    }
+}
@@ -0,0 +1,279 @@
+//! A map keyed by [`u8`], backed by a [`ByteSet`].
+//!
+//! See [`ByteMap`] for more info.
+//!
+//! [`u8`]: https://doc.rust-lang.org/std/primitive.u8.html
+//! [`ByteSet`]: ../struct.ByteSet.html
+//! [`ByteMap`]: struct.ByteMap.html
+
+use crate::ByteSet;
+use core::{mem, mem::MaybeUninit, ptr};
+
+// These modules must appear in this order to make documentation easier to read.
+// The space between ensures rustfmt does not reorder them.
+mod entry;
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
+
+mod iter;
+pub use iter::{IntoIter, Iter, IterMut, Values, ValuesMut};
+
+mod traits;
+
+/// A dense map keyed by [`u8`].
+///
+/// # Implementation
+///
+/// Values are stored inline in a 256-slot array indexed by key, and a
+/// [`ByteSet`] tracks which slots are occupied. This makes lookup a single bit
+/// test and iteration as fast as iterating over a [`ByteSet`], at the cost of
+/// reserving space for every possible key up front.
+///
+/// Entries are iterated in order of their keys, from least to greatest.
+///
+/// # Examples
+///
+/// ```
+/// use byte_set::ByteMap;
+///
+/// let mut map = ByteMap::new();
+/// map.insert(b'b', "bee");
+/// map.insert(b'a', "ay");
+///
+/// assert_eq!(map.get(b'a'), Some(&"ay"));
+/// assert_eq!(map.keys().len(), 2);
+///
+/// let entries: Vec<(u8, &&str)> = map.iter().collect();
+/// assert_eq!(entries, [(b'a', &"ay"), (b'b', &"bee")]);
+/// ```
+///
+/// [`u8`]: https://doc.rust-lang.org/std/primitive.u8.html
+/// [`ByteSet`]: ../struct.ByteSet.html
+pub struct ByteMap<V> {
+    /// The keys of initialized slots in `values`.
+    keys: ByteSet,
+    values: [MaybeUninit<V>; 256],
+}
+
+impl<V> ByteMap<V> {
+    /// Returns a map containing no entries.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            keys: ByteSet::new(),
+            // SAFETY: An array of `MaybeUninit` does not require
+            // initialization.
+            values: unsafe { MaybeUninit::uninit().assume_init() },
+        }
+    }
+
+    /// Returns the number of entries in `self`.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if `self` contains no entries.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the set of keys in `self`.
+    ///
+    /// Because this is an ordinary [`ByteSet`], it can be iterated over in
+    /// order or combined with other sets.
+    ///
+    /// [`ByteSet`]: ../struct.ByteSet.html
+    #[inline]
+    #[must_use]
+    pub const fn keys(&self) -> ByteSet {
+        self.keys
+    }
+
+    /// Returns `true` if `self` contains a value for `key`.
+    #[inline]
+    #[must_use]
+    pub const fn contains_key(&self, key: u8) -> bool {
+        self.keys.contains(key)
+    }
+
+    /// Returns a reference to the value for `key`, or `None` if there is none.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, key: u8) -> Option<&V> {
+        if self.contains_key(key) {
+            Some(unsafe { self.get_unchecked(key) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the value for `key`, or `None` if there
+    /// is none.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, key: u8) -> Option<&mut V> {
+        if self.contains_key(key) {
+            Some(unsafe { self.get_unchecked_mut(key) })
+        } else {
+            None
+        }
+    }
+
+    /// Inserts `value` for `key`, returning the previous value for `key` if
+    /// there was one.
+    #[inline]
+    pub fn insert(&mut self, key: u8, value: V) -> Option<V> {
+        if self.contains_key(key) {
+            Some(mem::replace(unsafe { self.get_unchecked_mut(key) }, value))
+        } else {
+            unsafe { self.insert_unchecked(key, value) };
+            None
+        }
+    }
+
+    /// Removes the value for `key` and returns it, or `None` if there is none.
+    #[inline]
+    pub fn remove(&mut self, key: u8) -> Option<V> {
+        if self.contains_key(key) {
+            Some(unsafe { self.remove_unchecked(key) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the entry for `key` for in-place manipulation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_set::ByteMap;
+    ///
+    /// let mut counts = ByteMap::new();
+    /// for &byte in b"hello" {
+    ///     *counts.entry(byte).or_insert(0) += 1;
+    /// }
+    ///
+    /// assert_eq!(counts.get(b'l'), Some(&2));
+    /// ```
+    #[inline]
+    pub fn entry(&mut self, key: u8) -> Entry<'_, V> {
+        if self.contains_key(key) {
+            Entry::Occupied(OccupiedEntry::new(self, key))
+        } else {
+            Entry::Vacant(VacantEntry::new(self, key))
+        }
+    }
+
+    /// Removes all entries from `self`.
+    pub fn clear(&mut self) {
+        // Take the keys first so that a panicking destructor leaks the rest of
+        // the values instead of dropping them twice.
+        let keys = mem::take(&mut self.keys);
+
+        if mem::needs_drop::<V>() {
+            for key in keys {
+                unsafe { ptr::drop_in_place(self.slot_mut(key)) };
+            }
+        }
+    }
+
+    /// Returns an iterator over the entries of `self`, ordered by key.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, V> {
+        Iter::new(self)
+    }
+
+    /// Returns an iterator over the entries of `self` with mutable references
+    /// to values, ordered by key.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, V> {
+        IterMut::new(self)
+    }
+
+    /// Returns an iterator over the values of `self`, ordered by key.
+    #[inline]
+    pub fn values(&self) -> Values<'_, V> {
+        Values::new(self.iter())
+    }
+
+    /// Returns an iterator over mutable references to the values of `self`,
+    /// ordered by key.
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, V> {
+        ValuesMut::new(self.iter_mut())
+    }
+
+    /// Returns a pointer to the slot for `key`, which may be uninitialized.
+    #[inline]
+    fn slot(&self, key: u8) -> *const V {
+        self.values[key as usize].as_ptr()
+    }
+
+    /// Returns a mutable pointer to the slot for `key`, which may be
+    /// uninitialized.
+    #[inline]
+    fn slot_mut(&mut self, key: u8) -> *mut V {
+        self.values[key as usize].as_mut_ptr()
+    }
+
+    /// Returns a reference to the value for `key`.
+    ///
+    /// # Safety
+    ///
+    /// `key` must be contained in `self.keys`.
+    #[inline]
+    unsafe fn get_unchecked(&self, key: u8) -> &V {
+        debug_assert!(self.contains_key(key));
+        &*self.slot(key)
+    }
+
+    /// Returns a mutable reference to the value for `key`.
+    ///
+    /// # Safety
+    ///
+    /// `key` must be contained in `self.keys`.
+    #[inline]
+    unsafe fn get_unchecked_mut(&mut self, key: u8) -> &mut V {
+        debug_assert!(self.contains_key(key));
+        &mut *self.slot_mut(key)
+    }
+
+    /// Inserts `value` for `key` and returns a reference to it.
+    ///
+    /// # Safety
+    ///
+    /// `key` must not be contained in `self.keys`.
+    #[inline]
+    unsafe fn insert_unchecked(&mut self, key: u8, value: V) -> &mut V {
+        debug_assert!(!self.contains_key(key));
+        self.keys.insert(key);
+
+        let slot = self.slot_mut(key);
+        slot.write(value);
+        &mut *slot
+    }
+
+    /// Removes the value for `key` and returns it.
+    ///
+    /// # Safety
+    ///
+    /// `key` must be contained in `self.keys`.
+    #[inline]
+    unsafe fn remove_unchecked(&mut self, key: u8) -> V {
+        debug_assert!(self.contains_key(key));
+        self.keys.remove(key);
+
+        self.slot(key).read()
+    }
+}
+
+impl<V> Drop for ByteMap<V> {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
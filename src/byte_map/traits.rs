@@ -0,0 +1,120 @@
+use super::ByteMap;
+use core::{fmt, iter::FromIterator, ops};
+
+impl<V> Default for ByteMap<V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Clone> Clone for ByteMap<V> {
+    fn clone(&self) -> Self {
+        let mut map = Self::new();
+        for (key, value) in self {
+            unsafe { map.insert_unchecked(key, value.clone()) };
+        }
+        map
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for ByteMap<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self).finish()
+    }
+}
+
+impl<V: PartialEq> PartialEq for ByteMap<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.keys == other.keys
+            && self.values().zip(other.values()).all(|(a, b)| a == b)
+    }
+}
+
+impl<V: Eq> Eq for ByteMap<V> {}
+
+impl<V> ops::Index<u8> for ByteMap<V> {
+    type Output = V;
+
+    /// Returns a reference to the value for `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is not in the map.
+    #[inline]
+    fn index(&self, key: u8) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+impl<V> Extend<(u8, V)> for ByteMap<V> {
+    fn extend<T: IntoIterator<Item = (u8, V)>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|(key, value)| {
+            self.insert(key, value);
+        });
+    }
+}
+
+impl<'a, V: Copy> Extend<(u8, &'a V)> for ByteMap<V> {
+    fn extend<T: IntoIterator<Item = (u8, &'a V)>>(&mut self, iter: T) {
+        self.extend(iter.into_iter().map(|(key, &value)| (key, value)));
+    }
+}
+
+impl<V> FromIterator<(u8, V)> for ByteMap<V> {
+    fn from_iter<T: IntoIterator<Item = (u8, V)>>(iter: T) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<V: serde::Serialize> serde::Serialize for ByteMap<V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, V: serde::Deserialize<'de>> serde::Deserialize<'de> for ByteMap<V> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use core::marker::PhantomData;
+
+        struct ByteMapVisitor<V>(PhantomData<V>);
+
+        impl<'de, V: serde::Deserialize<'de>> serde::de::Visitor<'de>
+            for ByteMapVisitor<V>
+        {
+            type Value = ByteMap<V>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map keyed by bytes")
+            }
+
+            fn visit_map<A>(
+                self,
+                mut access: A,
+            ) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut map = ByteMap::new();
+                while let Some((key, value)) = access.next_entry::<u8, V>()? {
+                    map.insert(key, value);
+                }
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_map(ByteMapVisitor(PhantomData))
+    }
+}
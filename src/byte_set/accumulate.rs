@@ -0,0 +1,129 @@
+use super::ByteSet;
+
+#[cfg(any(test, feature = "std"))]
+use std::io;
+
+/// Collects bytes into independent partial sets that are merged at the end.
+///
+/// Inserting consecutive bytes into one set makes each insert wait on the
+/// previous one whenever they land in the same chunk. Spreading the bytes
+/// across partial sets breaks that dependency so that inserts can run in
+/// parallel.
+struct Accumulator {
+    parts: [ByteSet; Self::NUM_PARTS],
+}
+
+impl Accumulator {
+    const NUM_PARTS: usize = 4;
+
+    /// The number of bytes between checks for whether all bytes were found.
+    const BLOCK_SIZE: usize = 4096;
+
+    #[inline]
+    const fn new() -> Self {
+        Self {
+            parts: [ByteSet::new(); Self::NUM_PARTS],
+        }
+    }
+
+    /// Adds all bytes in `bytes`, returning `true` if every byte has now been
+    /// found and the rest of the input can be skipped.
+    fn add(&mut self, bytes: &[u8]) -> bool {
+        for block in bytes.chunks(Self::BLOCK_SIZE) {
+            let mut groups = block.chunks_exact(Self::NUM_PARTS);
+
+            for group in &mut groups {
+                for (part, &byte) in self.parts.iter_mut().zip(group) {
+                    part.insert(byte);
+                }
+            }
+
+            let rest = groups.remainder();
+            for (part, &byte) in self.parts.iter_mut().zip(rest) {
+                part.insert(byte);
+            }
+
+            if self.finish().is_full() {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Returns the union of the partial sets.
+    #[inline]
+    fn finish(&self) -> ByteSet {
+        self.parts
+            .iter()
+            .fold(ByteSet::new(), |union, &part| union.union(part))
+    }
+}
+
+/// Building sets from the bytes of slices and streams.
+impl ByteSet {
+    /// Returns a set of all bytes that occur in `bytes`.
+    ///
+    /// This is faster than inserting each byte in turn, especially for large
+    /// slices. It also stops early once all 256 bytes have been found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let set = ByteSet::from_slice(b"hello world");
+    ///
+    /// assert_eq!(set, ByteSet::from(&b" dehlorw"[..]));
+    /// ```
+    #[must_use]
+    pub fn from_slice(bytes: &[u8]) -> Self {
+        let mut accumulator = Accumulator::new();
+        accumulator.add(bytes);
+        accumulator.finish()
+    }
+
+    /// Returns a set of all bytes read from `reader` until it reaches the end.
+    ///
+    /// Reading stops early once all 256 bytes have been found, so `reader` may
+    /// not be read to the end. Reads that fail with [`ErrorKind::Interrupted`]
+    /// are retried, and any other error is returned.
+    ///
+    /// This does its own buffering, so `reader` does not need to be wrapped in
+    /// a [`BufReader`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let input: &[u8] = b"GET /index.html HTTP/1.1\r\n";
+    /// let set = ByteSet::from_reader(input).unwrap();
+    ///
+    /// assert!(set.contains(b'\r'));
+    /// assert!(!set.contains(b'\t'));
+    /// ```
+    ///
+    /// [`ErrorKind::Interrupted`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.Interrupted
+    /// [`BufReader`]: https://doc.rust-lang.org/std/io/struct.BufReader.html
+    #[cfg(any(test, feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn from_reader<R: io::Read>(mut reader: R) -> io::Result<Self> {
+        let mut accumulator = Accumulator::new();
+        let mut buf = [0; 8 * 1024];
+
+        loop {
+            let len = match reader.read(&mut buf) {
+                Ok(0) => break,
+                Ok(len) => len,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {
+                    continue
+                }
+                Err(error) => return Err(error),
+            };
+
+            if accumulator.add(&buf[..len]) {
+                break;
+            }
+        }
+
+        Ok(accumulator.finish())
+    }
+}
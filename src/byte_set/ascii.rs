@@ -20,17 +20,7 @@ impl ByteSet {
     /// ```
     ///
     /// [`u8::is_ascii`]: https://doc.rust-lang.org/std/primitive.u8.html#method.is_ascii
-    pub const ASCII: Self = {
-        #[cfg(target_pointer_width = "64")]
-        {
-            Self([!0, !0, 0, 0])
-        }
-
-        #[cfg(not(target_pointer_width = "64"))]
-        {
-            Self([!0, !0, !0, !0, 0, 0, 0, 0])
-        }
-    };
+    pub const ASCII: Self = Self::from_range_to(..128);
 
     /// The set of all ASCII alphabetic characters:
     ///
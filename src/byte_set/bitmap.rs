@@ -0,0 +1,218 @@
+use super::{ByteSet, Chunk};
+use crate::chunk;
+
+/// Conversions to and from stable bitmap representations.
+///
+/// Unlike the [raw bytes](#method.as_raw_bytes), these have a fixed layout that
+/// is the same on all targets and will not change. Each is a 256-bit bitmap
+/// where bit `n` is set if byte `n` is contained, stored as follows:
+///
+/// - [`to_bitmap_le`](#method.to_bitmap_le): byte `i` holds bits `8 * i` to
+///   `8 * i + 7`, with bit `8 * i` as its least significant bit. This is the
+///   256-bit number in little-endian byte order.
+///
+/// - [`to_bitmap_be`](#method.to_bitmap_be): the reverse of the little-endian
+///   bitmap. This is the 256-bit number in big-endian byte order.
+///
+/// - [`to_u64_array`](#method.to_u64_array) and
+///   [`to_u128_array`](#method.to_u128_array): element `i` holds the `i`-th
+///   least significant word, so bit `n` is bit `n % 64` of element `n / 64` for
+///   `u64`, and likewise for `u128`.
+impl ByteSet {
+    /// Returns the 256-bit bitmap of `self` as four `u64` words, from least to
+    /// most significant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let words = ByteSet::from(&[0, 63, 64, 255][..]).to_u64_array();
+    ///
+    /// assert_eq!(words, [1 | 1 << 63, 1, 0, 1 << 63]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_u64_array(&self) -> [u64; 4] {
+        let mut words = [0; 4];
+
+        // Chunks are at most 64 bits, so each is within a single word.
+        let mut i = 0;
+        while i < Self::NUM_SLOTS {
+            let bit = i * chunk::INDEX_OFFSET;
+
+            // `Chunk` is `u64` on some targets.
+            #[allow(clippy::unnecessary_cast)]
+            let chunk = self.0[i] as u64;
+
+            words[bit / 64] |= chunk << (bit % 64);
+            i += 1;
+        }
+        words
+    }
+
+    /// Returns a set from a 256-bit bitmap of four `u64` words, from least to
+    /// most significant.
+    ///
+    /// This is the inverse of [`to_u64_array`](#method.to_u64_array).
+    #[inline]
+    #[must_use]
+    pub const fn from_u64_array(words: [u64; 4]) -> Self {
+        let mut chunks = [0; Self::NUM_SLOTS];
+
+        let mut i = 0;
+        while i < Self::NUM_SLOTS {
+            let bit = i * chunk::INDEX_OFFSET;
+            chunks[i] = (words[bit / 64] >> (bit % 64)) as Chunk;
+            i += 1;
+        }
+        Self(chunks)
+    }
+
+    /// Returns the 256-bit bitmap of `self` as two `u128` words, from least to
+    /// most significant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let words = ByteSet::from(&[0, 127, 128][..]).to_u128_array();
+    ///
+    /// assert_eq!(words, [1 | 1 << 127, 1]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_u128_array(&self) -> [u128; 2] {
+        let w = self.to_u64_array();
+        [
+            (w[0] as u128) | ((w[1] as u128) << 64),
+            (w[2] as u128) | ((w[3] as u128) << 64),
+        ]
+    }
+
+    /// Returns a set from a 256-bit bitmap of two `u128` words, from least to
+    /// most significant.
+    ///
+    /// This is the inverse of [`to_u128_array`](#method.to_u128_array).
+    #[inline]
+    #[must_use]
+    pub const fn from_u128_array(words: [u128; 2]) -> Self {
+        Self::from_u64_array([
+            words[0] as u64,
+            (words[0] >> 64) as u64,
+            words[1] as u64,
+            (words[1] >> 64) as u64,
+        ])
+    }
+
+    /// Returns the 256-bit bitmap of `self` in little-endian byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let bitmap = ByteSet::from(&[0, 9, 255][..]).to_bitmap_le();
+    ///
+    /// assert_eq!(bitmap[0], 0b0000_0001);
+    /// assert_eq!(bitmap[1], 0b0000_0010);
+    /// assert_eq!(bitmap[31], 0b1000_0000);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_bitmap_le(&self) -> [u8; 32] {
+        let words = self.to_u64_array();
+        let mut bitmap = [0; 32];
+
+        let mut i = 0;
+        while i < 32 {
+            bitmap[i] = (words[i / 8] >> (i % 8 * 8)) as u8;
+            i += 1;
+        }
+        bitmap
+    }
+
+    /// Returns a set from a 256-bit bitmap in little-endian byte order.
+    ///
+    /// This is the inverse of [`to_bitmap_le`](#method.to_bitmap_le).
+    #[inline]
+    #[must_use]
+    pub const fn from_bitmap_le(bitmap: [u8; 32]) -> Self {
+        let mut words = [0u64; 4];
+
+        let mut i = 0;
+        while i < 32 {
+            words[i / 8] |= (bitmap[i] as u64) << (i % 8 * 8);
+            i += 1;
+        }
+        Self::from_u64_array(words)
+    }
+
+    /// Returns the 256-bit bitmap of `self` in big-endian byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let bitmap = ByteSet::from(&[0, 9, 255][..]).to_bitmap_be();
+    ///
+    /// assert_eq!(bitmap[0], 0b1000_0000);
+    /// assert_eq!(bitmap[30], 0b0000_0010);
+    /// assert_eq!(bitmap[31], 0b0000_0001);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_bitmap_be(&self) -> [u8; 32] {
+        let le = self.to_bitmap_le();
+        let mut bitmap = [0; 32];
+
+        let mut i = 0;
+        while i < 32 {
+            bitmap[i] = le[31 - i];
+            i += 1;
+        }
+        bitmap
+    }
+
+    /// Returns a set from a 256-bit bitmap in big-endian byte order.
+    ///
+    /// This is the inverse of [`to_bitmap_be`](#method.to_bitmap_be).
+    #[inline]
+    #[must_use]
+    pub const fn from_bitmap_be(bitmap: [u8; 32]) -> Self {
+        let mut le = [0; 32];
+
+        let mut i = 0;
+        while i < 32 {
+            le[i] = bitmap[31 - i];
+            i += 1;
+        }
+        Self::from_bitmap_le(le)
+    }
+}
+
+impl From<[u64; 4]> for ByteSet {
+    #[inline]
+    fn from(words: [u64; 4]) -> Self {
+        Self::from_u64_array(words)
+    }
+}
+
+impl From<ByteSet> for [u64; 4] {
+    #[inline]
+    fn from(set: ByteSet) -> Self {
+        set.to_u64_array()
+    }
+}
+
+impl From<[u128; 2]> for ByteSet {
+    #[inline]
+    fn from(words: [u128; 2]) -> Self {
+        Self::from_u128_array(words)
+    }
+}
+
+impl From<ByteSet> for [u128; 2] {
+    #[inline]
+    fn from(set: ByteSet) -> Self {
+        set.to_u128_array()
+    }
+}
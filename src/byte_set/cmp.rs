@@ -0,0 +1,77 @@
+use super::ByteSet;
+use core::cmp::Ordering;
+
+/// Orders other than the lexicographic [`Ord`] implementation.
+///
+/// These can be used with methods like [`slice::sort_by`], or wrapped in a
+/// newtype to key a [`BTreeMap`] by a different order.
+///
+/// [`Ord`]:            struct.ByteSet.html#impl-Ord
+/// [`slice::sort_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by
+/// [`BTreeMap`]:       https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+impl ByteSet {
+    /// Compares `self` and `other` by their number of bytes, then by their
+    /// lexicographic [`Ord`] order.
+    ///
+    /// This is also known as shortlex order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// use core::cmp::Ordering;
+    ///
+    /// let ab = ByteSet::from(&b"ab"[..]);
+    /// let z = ByteSet::from(&b"z"[..]);
+    ///
+    /// assert_eq!(ab.cmp(&z), Ordering::Less);
+    /// assert_eq!(ab.cmp_by_len(&z), Ordering::Greater);
+    /// ```
+    ///
+    /// [`Ord`]: #impl-Ord
+    #[inline]
+    #[must_use]
+    pub fn cmp_by_len(&self, other: &Self) -> Ordering {
+        self.len().cmp(&other.len()).then_with(|| self.cmp(other))
+    }
+
+    /// Compares `self` and `other` colexicographically, by the greatest byte
+    /// in only one of them.
+    ///
+    /// This is the same as comparing the sets as 256-bit numbers where bit `n`
+    /// is set if `n` is contained, like their [bitmaps].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// use core::cmp::Ordering;
+    ///
+    /// let ab = ByteSet::from(&b"ab"[..]);
+    /// let ac = ByteSet::from(&b"ac"[..]);
+    /// let bc = ByteSet::from(&b"bc"[..]);
+    ///
+    /// assert_eq!(ab.cmp_colex(&ac), Ordering::Less);
+    /// assert_eq!(bc.cmp_colex(&ac), Ordering::Greater);
+    /// assert_eq!(bc.cmp(&ac), Ordering::Greater);
+    /// ```
+    ///
+    /// [bitmaps]: #method.to_u64_array
+    #[inline]
+    #[must_use]
+    pub const fn cmp_colex(&self, other: &Self) -> Ordering {
+        let mut i = Self::NUM_SLOTS;
+        while i > 0 {
+            i -= 1;
+
+            let (a, b) = (self.0[i], other.0[i]);
+            if a < b {
+                return Ordering::Less;
+            }
+            if a > b {
+                return Ordering::Greater;
+            }
+        }
+        Ordering::Equal
+    }
+}
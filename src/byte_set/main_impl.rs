@@ -1,4 +1,4 @@
-use super::{chunk_index_and_shift, ByteSet, Chunk};
+use super::{chunk_index_and_shift, vector, ByteSet, Chunk};
 use crate::chunk;
 use core::ops;
 
@@ -18,7 +18,7 @@ impl ByteSet {
     #[inline]
     #[must_use]
     pub const fn full() -> Self {
-        Self([Chunk::max_value(); Self::NUM_SLOTS])
+        Self([Chunk::MAX; Self::NUM_SLOTS])
     }
 
     /// Returns a set containing only `byte`.
@@ -52,7 +52,7 @@ impl ByteSet {
             // if this_chunk == byte_chunk {
             //     value
             // } else if this_chunk < byte_chunk {
-            //     Chunk::max_value()
+            //     Chunk::MAX
             // } else {
             //     0
             // }
@@ -62,29 +62,18 @@ impl ByteSet {
             let value: Chunk = (1 << shift) - 1;
             let is_equal = (this_chunk == byte_chunk) as usize;
             let is_less_than = (this_chunk < byte_chunk) as usize;
-            let if_unequal = [0, Chunk::max_value()][is_less_than];
+            let if_unequal = [0, Chunk::MAX][is_less_than];
 
             [if_unequal, value][is_equal]
         }
         let (index, shift) = chunk_index_and_shift(range.end);
-        #[cfg(target_pointer_width = "64")]
-        let array = [
-            chunk_for(0, index, shift),
-            chunk_for(1, index, shift),
-            chunk_for(2, index, shift),
-            chunk_for(3, index, shift),
-        ];
-        #[cfg(not(target_pointer_width = "64"))]
-        let array = [
-            chunk_for(0, index, shift),
-            chunk_for(1, index, shift),
-            chunk_for(2, index, shift),
-            chunk_for(3, index, shift),
-            chunk_for(4, index, shift),
-            chunk_for(5, index, shift),
-            chunk_for(6, index, shift),
-            chunk_for(7, index, shift),
-        ];
+
+        let mut array = [0; Self::NUM_SLOTS];
+        let mut i = 0;
+        while i < Self::NUM_SLOTS {
+            array[i] = chunk_for(i, index, shift);
+            i += 1;
+        }
         ByteSet(array)
     }
 
@@ -159,24 +148,8 @@ impl ByteSet {
     /// This is more efficient than checking `self.len() == 0`.
     #[inline]
     #[must_use]
-    #[allow(clippy::let_and_return)]
     pub const fn is_empty(&self) -> bool {
-        let is_empty = (self.0[0] == 0)
-            & (self.0[1] == 0)
-            & (self.0[2] == 0)
-            & (self.0[3] == 0);
-
-        #[cfg(not(target_pointer_width = "64"))]
-        {
-            is_empty
-                & (self.0[4] == 0)
-                & (self.0[5] == 0)
-                & (self.0[6] == 0)
-                & (self.0[7] == 0)
-        }
-
-        #[cfg(target_pointer_width = "64")]
-        is_empty
+        map_reduce_chunks!(self, self, |, |) == 0
     }
 
     /// Returns `true` if `self` contains all bytes.
@@ -184,48 +157,124 @@ impl ByteSet {
     /// This is more efficient than checking `self.len() == 256`.
     #[inline]
     #[must_use]
-    #[allow(clippy::let_and_return)]
     pub const fn is_full(&self) -> bool {
-        let is_full = (self.0[0] == !0)
-            & (self.0[1] == !0)
-            & (self.0[2] == !0)
-            & (self.0[3] == !0);
-
-        #[cfg(not(target_pointer_width = "64"))]
-        {
-            is_full
-                & (self.0[4] == !0)
-                & (self.0[5] == !0)
-                & (self.0[6] == !0)
-                & (self.0[7] == !0)
-        }
-
-        #[cfg(target_pointer_width = "64")]
-        is_full
+        map_reduce_chunks!(self, self, &, &) == !0
     }
 
     /// Returns the number of bytes contained in `self`.
     #[cfg_attr(target_feature = "popcnt", inline)]
     #[must_use]
-    #[allow(clippy::let_and_return)]
     pub const fn len(&self) -> usize {
-        let len = (self.0[0].count_ones() as usize)
-            + (self.0[1].count_ones() as usize)
-            + (self.0[2].count_ones() as usize)
-            + (self.0[3].count_ones() as usize);
-
-        #[cfg(not(target_pointer_width = "64"))]
-        {
-            len + (self.0[4].count_ones() as usize)
-                + (self.0[5].count_ones() as usize)
-                + (self.0[6].count_ones() as usize)
-                + (self.0[7].count_ones() as usize)
+        let mut len = 0;
+        let mut i = 0;
+        while i < Self::NUM_SLOTS {
+            len += self.0[i].count_ones() as usize;
+            i += 1;
         }
-
-        #[cfg(target_pointer_width = "64")]
         len
     }
 
+    /// Returns the number of bytes in `self` that are less than `byte`.
+    ///
+    /// `byte` does not need to be contained in `self`. If it is, this is its
+    /// position in the sorted order of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let set = ByteSet::from(&b"aeiou"[..]);
+    ///
+    /// assert_eq!(set.rank(b'a'), 0);
+    /// assert_eq!(set.rank(b'f'), 2);
+    /// assert_eq!(set.rank(u8::MAX), 5);
+    /// ```
+    #[cfg_attr(target_feature = "popcnt", inline)]
+    #[must_use]
+    pub const fn rank(&self, byte: u8) -> usize {
+        let (index, shift) = chunk_index_and_shift(byte);
+
+        // Bits below `byte` in its own chunk.
+        let mut rank =
+            (self.0[index] & ((1 << shift) - 1)).count_ones() as usize;
+
+        let mut i = 0;
+        while i < index {
+            rank += self.0[i].count_ones() as usize;
+            i += 1;
+        }
+        rank
+    }
+
+    /// Returns the `n`-th smallest byte in `self`, counting from 0, or `None`
+    /// if `self` contains `n` or fewer bytes.
+    ///
+    /// This is the inverse of [`rank`](#method.rank) for bytes contained in
+    /// `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let set = ByteSet::from(&b"aeiou"[..]);
+    ///
+    /// assert_eq!(set.select(0), Some(b'a'));
+    /// assert_eq!(set.select(2), Some(b'i'));
+    /// assert_eq!(set.select(5), None);
+    ///
+    /// for byte in set {
+    ///     assert_eq!(set.select(set.rank(byte)), Some(byte));
+    /// }
+    /// ```
+    #[cfg_attr(target_feature = "popcnt", inline)]
+    #[must_use]
+    pub const fn select(&self, n: usize) -> Option<u8> {
+        let mut n = n;
+        let mut i = 0;
+
+        while i < Self::NUM_SLOTS {
+            let mut chunk = self.0[i];
+            let len = chunk.count_ones() as usize;
+
+            if n < len {
+                // Clear the `n` lowest bits so that the selected bit is first.
+                while n > 0 {
+                    chunk &= chunk - 1;
+                    n -= 1;
+                }
+
+                let bit = chunk.trailing_zeros() as usize;
+                return Some((bit + i * chunk::INDEX_OFFSET) as u8);
+            }
+
+            n -= len;
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns the position of `byte` in the sorted order of `self`, or `None`
+    /// if `byte` is not contained in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let set = ByteSet::from(&b"aeiou"[..]);
+    ///
+    /// assert_eq!(set.index_of(b'o'), Some(3));
+    /// assert_eq!(set.index_of(b'b'), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn index_of(&self, byte: u8) -> Option<usize> {
+        if self.contains(byte) {
+            Some(self.rank(byte))
+        } else {
+            None
+        }
+    }
+
     /// Removes all bytes from `self`.
     #[inline]
     pub fn clear(&mut self) {
@@ -295,18 +344,7 @@ impl ByteSet {
     /// Inserts all bytes of `other` into `self` in-place.
     #[inline]
     pub fn insert_all(&mut self, other: Self) {
-        self.0[0] |= other.0[0];
-        self.0[1] |= other.0[1];
-        self.0[2] |= other.0[2];
-        self.0[3] |= other.0[3];
-
-        #[cfg(not(target_pointer_width = "64"))]
-        {
-            self.0[4] |= other.0[4];
-            self.0[5] |= other.0[5];
-            self.0[6] |= other.0[6];
-            self.0[7] |= other.0[7];
-        }
+        *self = vector::union(self, &other);
     }
 
     /// Returns a copy of `self` with `byte` inserted.
@@ -346,7 +384,7 @@ impl ByteSet {
     /// Removes all bytes of `other` from `self` in-place.
     #[inline]
     pub fn remove_all(&mut self, other: Self) {
-        *self &= !other;
+        *self = vector::difference(self, &other);
     }
 
     /// Returns a copy of `self` with `byte` removed.
@@ -395,18 +433,12 @@ impl ByteSet {
         self.0[index] & (1 << shift) != 0
     }
 
-    #[inline]
-    #[must_use]
-    const fn chunk_and_or(&self, other: &Self) -> Chunk {
-        map_reduce_chunks!(self, other, &, |)
-    }
-
     /// Returns `true` if `self` contains any bytes in `other`.
     #[inline]
     #[must_use]
-    // Not `const` because it may be later improved with SIMD intrinsics.
+    // Not `const` because it uses SIMD intrinsics.
     pub fn contains_any(&self, other: &Self) -> bool {
-        self.chunk_and_or(other) != 0
+        !vector::is_disjoint(self, other)
     }
 
     #[inline]
@@ -417,9 +449,9 @@ impl ByteSet {
     /// Returns `true` if `other` contains all bytes in `self`.
     #[inline]
     #[must_use]
-    // Not `const` because it may be later improved with SIMD intrinsics.
+    // Not `const` because it uses SIMD intrinsics.
     pub fn is_subset(&self, other: &Self) -> bool {
-        self._is_subset(other)
+        vector::is_subset(self, other)
     }
 
     /// Returns `true` if `other` contains all bytes in `self` and at least one
@@ -428,11 +460,11 @@ impl ByteSet {
     /// This is also known as a "proper subset".
     #[must_use]
     // Not inlined because lots of code is generated on x86.
-    // Not `const` because it may be later improved with SIMD intrinsics.
+    // Not `const` because it uses SIMD intrinsics.
     pub fn is_strict_subset(&self, other: &Self) -> bool {
         // On x86, checking inequality first produces less code and uses fewer
         // registers.
-        self.ne(other) && self.is_subset(other)
+        !vector::eq(self, other) && self.is_subset(other)
     }
 
     /// Returns `true` if `self` contains all bytes in `other`.
@@ -455,9 +487,9 @@ impl ByteSet {
     /// Returns `true` if `self` and `other` have no bytes in common.
     #[inline]
     #[must_use]
-    // Not `const` because it may be later improved with SIMD intrinsics.
+    // Not `const` because it uses SIMD intrinsics.
     pub fn is_disjoint(&self, other: &Self) -> bool {
-        self.intersection(*other).is_empty()
+        vector::is_disjoint(self, other)
     }
 
     /// Returns a set with the bytes contained in `self`, but not in `other`.
@@ -510,20 +542,13 @@ impl ByteSet {
     #[cfg_attr(target_arch = "aarch64", inline)]
     // Not inlined because lots of code is generated on x86.
     pub const fn reverse_bits(self) -> Self {
-        Self([
-            #[cfg(not(target_pointer_width = "64"))]
-            self.0[7].reverse_bits(),
-            #[cfg(not(target_pointer_width = "64"))]
-            self.0[6].reverse_bits(),
-            #[cfg(not(target_pointer_width = "64"))]
-            self.0[5].reverse_bits(),
-            #[cfg(not(target_pointer_width = "64"))]
-            self.0[4].reverse_bits(),
-            self.0[3].reverse_bits(),
-            self.0[2].reverse_bits(),
-            self.0[1].reverse_bits(),
-            self.0[0].reverse_bits(),
-        ])
+        let mut chunks = [0; Self::NUM_SLOTS];
+        let mut i = 0;
+        while i < Self::NUM_SLOTS {
+            chunks[i] = self.0[Self::LAST_SLOT_INDEX - i].reverse_bits();
+            i += 1;
+        }
+        Self(chunks)
     }
 
     /// Returns `true` if `self` and `other` contain the same bytes.
@@ -532,23 +557,9 @@ impl ByteSet {
     #[inline]
     #[must_use]
     #[allow(clippy::should_implement_trait)]
-    #[allow(clippy::let_and_return)]
     pub const fn eq(&self, other: &Self) -> bool {
-        let eq = (self.0[0] == other.0[0])
-            & (self.0[1] == other.0[1])
-            & (self.0[2] == other.0[2])
-            & (self.0[3] == other.0[3]);
-
-        #[cfg(not(target_pointer_width = "64"))]
-        {
-            eq & (self.0[4] == other.0[4])
-                & (self.0[5] == other.0[5])
-                & (self.0[6] == other.0[6])
-                & (self.0[7] == other.0[7])
-        }
-
-        #[cfg(target_pointer_width = "64")]
-        eq
+        // Branchless, so that it compiles to a vector compare.
+        map_reduce_chunks!(self, other, ^, |) == 0
     }
 
     /// Returns `true` if `self` and `other` do not contain the same bytes.
@@ -1,4 +1,4 @@
-use crate::Chunk;
+use crate::{chunk, Chunk};
 use core::mem;
 
 // These modules must appear in this order to make documentation easier to read.
@@ -7,8 +7,37 @@ mod main_impl;
 
 mod ascii;
 
+mod accumulate;
+
+mod order;
+
+mod cmp;
+
+mod ranges;
+pub use ranges::Ranges;
+
+mod search;
+pub use search::Positions;
+
+mod split;
+pub use split::{RSplit, Split, SplitInclusive, SplitN, Tokens};
+
+mod trim;
+
+mod validate;
+pub use validate::InvalidByte;
+
+mod text;
+pub use text::{ParseError, ParseErrorReason};
+
+mod regex;
+
+mod bitmap;
+
 mod raw;
 
+pub(crate) mod vector;
+
 mod traits;
 
 /// An efficient, general-purpose set of [`u8`]s.
@@ -21,13 +50,22 @@ mod traits;
 /// the last byte.
 ///
 /// The mask is composed a of "chunk" array. Each chunk is either 64 or 32 bits
-/// wide, depending on the target architecture. As of right now, this is based
-/// on native register size. This may change in the future based on target
-/// features that enable better performance.
+/// wide, depending on whether the target architecture has native 64-bit
+/// instructions. The whole mask is aligned to 32 bytes, so that it can be
+/// loaded as one AVX2 register or two SSE2, NEON, or WebAssembly SIMD
+/// registers.
+///
+/// The operators (`|`, `&`, `-`, `^`, and `!`) and methods that are not
+/// `const`, such as [`is_subset`](#method.is_subset), use explicit
+/// vector instructions for whichever of these target features are enabled at
+/// compile time, and plain chunk operations otherwise. The `const` methods,
+/// such as [`union`](#method.union) and [`len`](#method.len), cannot use
+/// intrinsics, so they loop over the chunks and rely on the compiler to
+/// vectorize them.
 ///
 /// [`u8`]: https://doc.rust-lang.org/std/primitive.u8.html
 #[derive(Clone, Copy, PartialEq, Eq)]
-#[repr(C)]
+#[repr(C, align(32))]
 pub struct ByteSet(pub(crate) [Chunk; Self::NUM_SLOTS]);
 
 /// Returns the chunk index for `byte` and the bit shift for that chunk.
@@ -35,15 +73,9 @@ pub struct ByteSet(pub(crate) [Chunk; Self::NUM_SLOTS]);
 const fn chunk_index_and_shift(byte: u8) -> (usize, usize) {
     let byte = byte as usize;
 
-    #[cfg(target_pointer_width = "64")]
-    let index = byte >> 6;
-    #[cfg(target_pointer_width = "64")]
-    let shift = byte & 0b0011_1111;
-
-    #[cfg(not(target_pointer_width = "64"))]
-    let index = byte >> 5;
-    #[cfg(not(target_pointer_width = "64"))]
-    let shift = byte & 0b0001_1111;
+    // `INDEX_OFFSET` is a power of two, so these are a shift and a mask.
+    let index = byte / chunk::INDEX_OFFSET;
+    let shift = byte % chunk::INDEX_OFFSET;
 
     (index, shift)
 }
@@ -55,29 +87,3 @@ impl ByteSet {
 
     pub(crate) const LAST_SLOT_INDEX: usize = Self::NUM_SLOTS - 1;
 }
-
-#[cfg(test)]
-impl ByteSet {
-    /// Returns a formatting proxy for the binary representation of `self`.
-    ///
-    /// `fmt::Binary` is not currently implemented for `ByteSet` because of the
-    /// extra work to support formatting options.
-    pub(crate) fn fmt_binary<'a>(&'a self) -> impl core::fmt::Display + 'a {
-        struct Formatted<'a>(&'a ByteSet);
-
-        impl core::fmt::Display for Formatted<'_> {
-            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-                for chunk in &(self.0).0 {
-                    #[cfg(target_pointer_width = "64")]
-                    write!(f, "{:064b}", chunk)?;
-
-                    #[cfg(not(target_pointer_width = "64"))]
-                    write!(f, "{:032b}", chunk)?;
-                }
-                Ok(())
-            }
-        }
-
-        Formatted(self)
-    }
-}
@@ -0,0 +1,164 @@
+use super::{chunk_index_and_shift, ByteSet, Chunk};
+use crate::{chunk, Iter};
+use core::ops::{Bound, RangeBounds};
+
+/// Ordered navigation, like that of [`BTreeSet`].
+///
+/// Each operation masks `self` with a set of the requested range, so its cost
+/// does not depend on the size of the range.
+///
+/// [`BTreeSet`]: https://doc.rust-lang.org/std/collections/struct.BTreeSet.html
+impl ByteSet {
+    /// Returns a set containing all bytes in `range`.
+    ///
+    /// Decreasing ranges produce an empty set.
+    #[inline]
+    pub(crate) fn from_range_bounds<R: RangeBounds<u8>>(range: &R) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => Self::from_range_from(start..),
+            Bound::Excluded(&start) => match start.checked_add(1) {
+                Some(start) => Self::from_range_from(start..),
+                None => return Self::new(),
+            },
+            Bound::Unbounded => Self::full(),
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(&end) => Self::from_range_to_inclusive(..=end),
+            Bound::Excluded(&end) => Self::from_range_to(..end),
+            Bound::Unbounded => Self::full(),
+        };
+
+        start.intersection(end)
+    }
+
+    /// Returns an iterator over the bytes in `self` that are within `range`,
+    /// from least to greatest.
+    ///
+    /// Unlike [`BTreeSet::range`], this does not panic if `range` is
+    /// decreasing. It yields no bytes instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let set = ByteSet::ASCII_ALPHANUMERIC;
+    /// let bytes: Vec<u8> = set.range(b'7'..=b'B').collect();
+    ///
+    /// assert_eq!(bytes, b"789AB");
+    /// ```
+    ///
+    /// [`BTreeSet::range`]: https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.range
+    #[inline]
+    pub fn range<R: RangeBounds<u8>>(&self, range: R) -> Iter {
+        self.intersection(Self::from_range_bounds(&range))
+            .into_iter()
+    }
+
+    /// Returns the number of bytes in `self` that are within `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let set = ByteSet::ASCII_GRAPHIC;
+    ///
+    /// assert_eq!(set.len_in(b'a'..=b'z'), 26);
+    /// assert_eq!(set.len_in(..b' '), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn len_in<R: RangeBounds<u8>>(&self, range: R) -> usize {
+        self.intersection(Self::from_range_bounds(&range)).len()
+    }
+
+    /// Returns the least byte in `self` that is greater than `byte`, or `None`
+    /// if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let set = ByteSet::ASCII_DIGIT;
+    ///
+    /// assert_eq!(set.next_after(b'4'), Some(b'5'));
+    /// assert_eq!(set.next_after(b' '), Some(b'0'));
+    /// assert_eq!(set.next_after(b'9'), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn next_after(&self, byte: u8) -> Option<u8> {
+        let start = byte.checked_add(1)?;
+        self.intersection(Self::from_range_from(start..)).first()
+    }
+
+    /// Returns the greatest byte in `self` that is less than `byte`, or `None`
+    /// if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let set = ByteSet::ASCII_DIGIT;
+    ///
+    /// assert_eq!(set.prev_before(b'4'), Some(b'3'));
+    /// assert_eq!(set.prev_before(b'z'), Some(b'9'));
+    /// assert_eq!(set.prev_before(b'0'), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn prev_before(&self, byte: u8) -> Option<u8> {
+        self.intersection(Self::from_range_to(..byte)).last()
+    }
+
+    /// Returns the least byte not in `self` that is greater than or equal to
+    /// `byte`, or `None` if `self` contains all bytes from `byte` onward.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let set = ByteSet::ASCII_DIGIT;
+    ///
+    /// assert_eq!(set.next_absent_from(b'4'), Some(b':'));
+    /// assert_eq!(set.next_absent_from(b' '), Some(b' '));
+    /// assert_eq!(ByteSet::full().next_absent_from(0), None);
+    /// ```
+    #[must_use]
+    pub fn next_absent_from(&self, byte: u8) -> Option<u8> {
+        let (mut index, shift) = chunk_index_and_shift(byte);
+        let mut absent = !self.0[index] & (Chunk::MAX << shift);
+
+        loop {
+            if let Some(lsb) = chunk::lsb(absent) {
+                return Some(lsb + (index * chunk::INDEX_OFFSET) as u8);
+            }
+
+            index += 1;
+            if index == Self::NUM_SLOTS {
+                return None;
+            }
+            absent = !self.0[index];
+        }
+    }
+
+    /// Splits `self` in two at `byte`, returning all bytes greater than or
+    /// equal to `byte` and leaving the rest in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let mut lower = ByteSet::ASCII_HEXDIGIT;
+    /// let upper = lower.split_off(b'A');
+    ///
+    /// assert_eq!(lower, ByteSet::ASCII_DIGIT);
+    /// assert_eq!(upper.len(), 12);
+    /// ```
+    #[inline]
+    pub fn split_off(&mut self, byte: u8) -> Self {
+        let upper = self.intersection(Self::from_range_from(byte..));
+        self.remove_all(upper);
+        upper
+    }
+}
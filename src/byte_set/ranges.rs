@@ -0,0 +1,231 @@
+use super::{chunk_index_and_shift, ByteSet, Chunk};
+use crate::chunk;
+use core::{fmt, iter, ops::RangeInclusive};
+
+/// Operations over contiguous runs of bytes.
+impl ByteSet {
+    /// Returns a set containing the first byte of each contiguous run in
+    /// `self`.
+    ///
+    /// A byte starts a run if it is contained in `self` but the byte before it
+    /// is not.
+    #[inline]
+    fn run_starts(&self) -> Self {
+        let mut starts = Self::new();
+
+        // The last bit of the previous chunk, shifted into the next chunk.
+        let mut carry: Chunk = 0;
+
+        for (start, &chunk) in starts.0.iter_mut().zip(&self.0) {
+            *start = chunk & !((chunk << 1) | carry);
+            carry = chunk >> (chunk::INDEX_OFFSET - 1);
+        }
+        starts
+    }
+
+    /// Returns the last byte not contained in `self` that is less than or equal
+    /// to `byte`, or `None` if there is none.
+    #[inline]
+    fn last_absent_to(&self, byte: u8) -> Option<u8> {
+        let (mut index, shift) = chunk_index_and_shift(byte);
+        let mask = Chunk::MAX >> (chunk::INDEX_OFFSET - 1 - shift);
+        let mut absent = !self.0[index] & mask;
+
+        loop {
+            if let Some(msb) = chunk::msb(absent) {
+                return Some(msb + (index * chunk::INDEX_OFFSET) as u8);
+            }
+
+            if index == 0 {
+                return None;
+            }
+            index -= 1;
+            absent = !self.0[index];
+        }
+    }
+
+    /// Returns an iterator over the contiguous runs of bytes in `self`, from
+    /// least to greatest.
+    ///
+    /// Each run is as long as possible, so consecutive runs are always
+    /// separated by at least one byte not contained in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let set = ByteSet::ASCII_CONTROL;
+    /// let ranges: Vec<_> = set.ranges().collect();
+    ///
+    /// assert_eq!(ranges, [0x00..=0x1F, 0x7F..=0x7F]);
+    /// ```
+    #[inline]
+    pub fn ranges(&self) -> Ranges {
+        Ranges {
+            byte_set: *self,
+            len: self.run_starts().len(),
+        }
+    }
+
+    /// Returns an iterator over the contiguous runs of bytes not contained in
+    /// `self`, from least to greatest.
+    ///
+    /// This is equivalent to `self.not().ranges()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let set = ByteSet::ASCII_ALPHANUMERIC;
+    /// let gaps: Vec<_> = set.gaps().collect();
+    ///
+    /// assert_eq!(
+    ///     gaps,
+    ///     [0x00..=0x2F, 0x3A..=0x40, 0x5B..=0x60, 0x7B..=0xFF],
+    /// );
+    /// ```
+    #[inline]
+    pub fn gaps(&self) -> Ranges {
+        self.not().ranges()
+    }
+
+    /// Returns `true` if `self` is a single non-empty run of consecutive bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// assert!(ByteSet::ASCII_DIGIT.is_contiguous());
+    /// assert!(!ByteSet::ASCII_HEXDIGIT.is_contiguous());
+    ///
+    /// // There is no run in an empty set.
+    /// assert!(!ByteSet::new().is_contiguous());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_contiguous(&self) -> bool {
+        self.as_range().is_some()
+    }
+
+    /// Returns the range of bytes in `self` if it is a single non-empty run of
+    /// consecutive bytes, or `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// assert_eq!(ByteSet::ASCII_DIGIT.as_range(), Some(b'0'..=b'9'));
+    /// assert_eq!(ByteSet::ASCII_HEXDIGIT.as_range(), None);
+    /// ```
+    #[must_use]
+    pub fn as_range(&self) -> Option<RangeInclusive<u8>> {
+        let first = self.first()?;
+        let last = self.last()?;
+
+        if (last - first) as usize + 1 == self.len() {
+            Some(first..=last)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a set containing all bytes in each range of `ranges`.
+    ///
+    /// Ranges may overlap and may be in any order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let ranges = vec![b'a'..=b'z', b'A'..=b'Z', b'0'..=b'9'];
+    /// let set = ByteSet::from_ranges(ranges);
+    ///
+    /// assert_eq!(set, ByteSet::ASCII_ALPHANUMERIC);
+    /// ```
+    pub fn from_ranges<I>(ranges: I) -> Self
+    where
+        I: IntoIterator<Item = RangeInclusive<u8>>,
+    {
+        ranges.into_iter().fold(Self::new(), |set, range| {
+            set.union(Self::from_range_inclusive(range))
+        })
+    }
+}
+
+/// An iterator over the contiguous runs of bytes in a [`ByteSet`].
+///
+/// This is created by [`ByteSet::ranges`] and [`ByteSet::gaps`].
+///
+/// [`ByteSet`]: struct.ByteSet.html
+/// [`ByteSet::ranges`]: struct.ByteSet.html#method.ranges
+/// [`ByteSet::gaps`]:   struct.ByteSet.html#method.gaps
+#[derive(Clone)]
+pub struct Ranges {
+    /// The bytes of the runs not yet iterated over.
+    byte_set: ByteSet,
+
+    /// The number of runs not yet iterated over.
+    len: usize,
+}
+
+impl Iterator for Ranges {
+    type Item = RangeInclusive<u8>;
+
+    fn next(&mut self) -> Option<RangeInclusive<u8>> {
+        let start = self.byte_set.first()?;
+
+        let end = match self.byte_set.next_absent_from(start) {
+            // `start` is present, so `absent` is greater than it.
+            Some(absent) => absent - 1,
+            None => u8::MAX,
+        };
+
+        self.byte_set
+            .remove_all(ByteSet::from_range_inclusive(start..=end));
+        self.len -= 1;
+        Some(start..=end)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<RangeInclusive<u8>> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for Ranges {
+    fn next_back(&mut self) -> Option<RangeInclusive<u8>> {
+        let end = self.byte_set.last()?;
+
+        let start = match self.byte_set.last_absent_to(end) {
+            // `end` is present, so `absent` is less than it.
+            Some(absent) => absent + 1,
+            None => 0,
+        };
+
+        self.byte_set
+            .remove_all(ByteSet::from_range_inclusive(start..=end));
+        self.len -= 1;
+        Some(start..=end)
+    }
+}
+
+impl ExactSizeIterator for Ranges {}
+
+// `Ranges` does not produce more values after `None` is reached.
+impl iter::FusedIterator for Ranges {}
+
+impl fmt::Debug for Ranges {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
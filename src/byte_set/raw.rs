@@ -5,6 +5,9 @@ use core::{mem, slice};
 ///
 /// There are currently no stability guarantees over the internal bytes. This is
 /// being tracked in [#8](https://github.com/nvzqz/byte-set-rs/issues/8).
+///
+/// For a layout that is the same on all targets, use
+/// [`to_bitmap_le`](#method.to_bitmap_le) and related methods instead.
 impl ByteSet {
     const SIZE: usize = mem::size_of::<Self>();
 
@@ -0,0 +1,327 @@
+use super::{
+    text::{parse_hex_byte, ParseError, ParseErrorReason},
+    ByteSet,
+};
+
+#[cfg(any(test, feature = "alloc"))]
+extern crate alloc;
+#[cfg(any(test, feature = "alloc"))]
+use alloc::string::String;
+
+/// POSIX class names and the sets they stand for within a bracket expression.
+const POSIX_CLASSES: [(&str, ByteSet); 14] = [
+    ("alnum", ByteSet::ASCII_ALPHANUMERIC),
+    ("alpha", ByteSet::ASCII_ALPHABETIC),
+    ("ascii", ByteSet::ASCII),
+    ("blank", byte_set![b' ', b'\t']),
+    ("cntrl", ByteSet::ASCII_CONTROL),
+    ("digit", ByteSet::ASCII_DIGIT),
+    ("graph", ByteSet::ASCII_GRAPHIC),
+    ("lower", ByteSet::ASCII_LOWERCASE),
+    ("print", ByteSet::ASCII_GRAPHIC.inserting(b' ')),
+    ("punct", ByteSet::ASCII_PUNCTUATION),
+    ("space", SPACE),
+    ("upper", ByteSet::ASCII_UPPERCASE),
+    ("word", WORD),
+    ("xdigit", ByteSet::ASCII_HEXDIGIT),
+];
+
+/// The POSIX `[:space:]` class, which unlike [`ByteSet::ASCII_WHITESPACE`]
+/// includes vertical tab (`0x0B`).
+///
+/// [`ByteSet::ASCII_WHITESPACE`]: struct.ByteSet.html#associatedconstant.ASCII_WHITESPACE
+const SPACE: ByteSet = ByteSet::ASCII_WHITESPACE.inserting(0x0B);
+
+/// The `[:word:]` and `\w` class.
+const WORD: ByteSet = ByteSet::ASCII_ALPHANUMERIC.inserting(b'_');
+
+/// Appends the items of a bracket expression matching `set` to `class`.
+#[cfg(any(test, feature = "alloc"))]
+fn push_class_items(class: &mut String, set: ByteSet) {
+    fn push_byte(class: &mut String, byte: u8) {
+        use core::fmt::Write;
+
+        match byte {
+            b']' | b'[' | b'\\' | b'^' | b'-' => {
+                class.push('\\');
+                class.push(byte as char);
+            }
+            b' ' => class.push(' '),
+            _ if byte.is_ascii_graphic() => class.push(byte as char),
+            // Writing to a `String` never fails.
+            _ => drop(write!(class, "\\x{:02X}", byte)),
+        }
+    }
+
+    for range in set.ranges() {
+        let (start, end) = range.into_inner();
+        push_byte(class, start);
+
+        match end - start {
+            0 => {}
+            1 => push_byte(class, end),
+            _ => {
+                class.push('-');
+                push_byte(class, end);
+            }
+        }
+    }
+}
+
+/// Converting to and from regular expression character classes.
+impl ByteSet {
+    /// Returns a regular expression bracket expression that matches exactly
+    /// the bytes in `self`.
+    ///
+    /// This is the shorter of the positive (`[...]`) and negated (`[^...]`)
+    /// forms, preferring the positive form. Within the brackets, `]`, `[`,
+    /// `\`, `^`, and `-` are escaped with `\`, and bytes that are not printable
+    /// ASCII are written as `\xHH`.
+    ///
+    /// Bytes above `0x7F` are written as `\x80` through `\xFF`, which only
+    /// match those bytes in regex engines that match bytes rather than
+    /// Unicode scalar values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let not_digit = ByteSet::ASCII_DIGIT.not();
+    /// assert_eq!(not_digit.to_regex_class(), "[^0-9]");
+    ///
+    /// let non_ascii = ByteSet::ASCII.not().inserting(0);
+    /// assert_eq!(non_ascii.to_regex_class(), r"[^\x01-\x7F]");
+    ///
+    /// let ident = ByteSet::ASCII_ALPHANUMERIC.inserting(b'_');
+    /// assert_eq!(ident.to_regex_class(), "[0-9A-Z_a-z]");
+    /// ```
+    #[cfg(any(test, feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    pub fn to_regex_class(&self) -> String {
+        let mut positive = String::from("[");
+        push_class_items(&mut positive, *self);
+        positive.push(']');
+
+        let mut negated = String::from("[^");
+        push_class_items(&mut negated, self.not());
+        negated.push(']');
+
+        // An empty bracket expression is not valid.
+        let use_positive = !self.is_empty()
+            && (self.is_full() || positive.len() <= negated.len());
+
+        if use_positive {
+            positive
+        } else {
+            negated
+        }
+    }
+
+    /// Parses a regular expression bracket expression, such as `[^a-z_]`.
+    ///
+    /// Within the brackets, this supports:
+    ///
+    /// - Printable ASCII characters, which stand for themselves.
+    /// - Ranges such as `a-z`. A `-` that is first or last is itself.
+    ///   Classes cannot be the start or end of a range.
+    /// - A `]` that is first, which is itself.
+    /// - Escapes of ASCII punctuation, such as `\]` and `\-`.
+    /// - `\n`, `\r`, `\t`, `\f`, `\v`, and `\xHH` in hexadecimal.
+    /// - The Perl classes `\d`, `\s`, and `\w`, and their negations `\D`,
+    ///   `\S`, and `\W`, with their ASCII meanings.
+    /// - POSIX classes such as `[:alpha:]`, `[:digit:]`, and `[:space:]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let set = ByteSet::from_regex_class("[[:alpha:]_]").unwrap();
+    /// assert_eq!(set, ByteSet::ASCII_ALPHABETIC.inserting(b'_'));
+    ///
+    /// let set = ByteSet::from_regex_class(r"[^\x00-\x1F\x7F]").unwrap();
+    /// assert_eq!(set, ByteSet::ASCII_CONTROL.not());
+    ///
+    /// assert!(ByteSet::from_regex_class("[[:nope:]]").is_err());
+    /// ```
+    pub fn from_regex_class(class: &str) -> Result<Self, ParseError> {
+        RegexParser { s: class, pos: 0 }.parse()
+    }
+}
+
+/// A single item of a bracket expression that is not a range.
+enum Atom {
+    Byte(u8),
+    Class(ByteSet),
+}
+
+/// Parses a regular expression bracket expression into a [`ByteSet`].
+///
+/// [`ByteSet`]: struct.ByteSet.html
+struct RegexParser<'a> {
+    s: &'a str,
+    /// The byte offset into `s` of the next character to parse.
+    pos: usize,
+}
+
+impl RegexParser<'_> {
+    fn parse(&mut self) -> Result<ByteSet, ParseError> {
+        if self.peek() != Some(b'[') {
+            return Err(self.error(ParseErrorReason::MissingOpenBracket));
+        }
+        self.pos += 1;
+
+        let negated = self.peek() == Some(b'^');
+        if negated {
+            self.pos += 1;
+        }
+
+        let items_start = self.pos;
+        let mut set = ByteSet::new();
+
+        loop {
+            match self.peek() {
+                None => {
+                    let reason = ParseErrorReason::MissingCloseBracket;
+                    return Err(self.error(reason));
+                }
+                Some(b']') if self.pos != items_start => {
+                    self.pos += 1;
+                    break;
+                }
+                _ => {}
+            }
+
+            let start_pos = self.pos;
+            let start = self.atom()?;
+
+            // A `-` before the closing `]` is itself.
+            let bytes = self.s.as_bytes();
+            let is_range = bytes.get(self.pos) == Some(&b'-')
+                && !matches!(bytes.get(self.pos + 1), None | Some(b']'));
+
+            let start = match (start, is_range) {
+                (Atom::Byte(byte), true) => byte,
+                (Atom::Byte(byte), false) => {
+                    set.insert(byte);
+                    continue;
+                }
+                (Atom::Class(class), false) => {
+                    set.insert_all(class);
+                    continue;
+                }
+                (Atom::Class(_), true) => {
+                    let reason = ParseErrorReason::ClassInRange;
+                    return Err(ParseError::new(start_pos + 1, reason));
+                }
+            };
+
+            self.pos += 1;
+            let end = match self.atom()? {
+                Atom::Byte(byte) => byte,
+                Atom::Class(_) => {
+                    let reason = ParseErrorReason::ClassInRange;
+                    return Err(ParseError::new(start_pos + 1, reason));
+                }
+            };
+
+            if end < start {
+                let reason = ParseErrorReason::DecreasingRange { start, end };
+                return Err(ParseError::new(start_pos + 1, reason));
+            }
+
+            set.insert_all(ByteSet::from_range_inclusive(start..=end));
+        }
+
+        if self.pos != self.s.len() {
+            return Err(self.unexpected_char());
+        }
+
+        Ok(if negated { set.not() } else { set })
+    }
+
+    #[inline]
+    fn peek(&self) -> Option<u8> {
+        self.s.as_bytes().get(self.pos).cloned()
+    }
+
+    /// Returns an error for the character at the current position.
+    #[inline]
+    fn error(&self, reason: ParseErrorReason) -> ParseError {
+        ParseError::new(self.pos + 1, reason)
+    }
+
+    /// Returns an error for an unexpected character at the current position.
+    fn unexpected_char(&self) -> ParseError {
+        // Everything before `pos` is ASCII, so this is a `char` boundary.
+        let c = self.s[self.pos..].chars().next().unwrap_or_default();
+        self.error(ParseErrorReason::UnexpectedChar(c))
+    }
+
+    /// Parses a single byte or class within the brackets.
+    fn atom(&mut self) -> Result<Atom, ParseError> {
+        let rest = &self.s[self.pos..];
+
+        if rest.starts_with("[:") {
+            return self.posix_class().map(Atom::Class);
+        }
+
+        match self.peek() {
+            Some(b'\\') => self.escape(),
+            Some(byte) if byte == b' ' || byte.is_ascii_graphic() => {
+                self.pos += 1;
+                Ok(Atom::Byte(byte))
+            }
+            _ => Err(self.unexpected_char()),
+        }
+    }
+
+    /// Parses a POSIX class such as `[:alpha:]`.
+    fn posix_class(&mut self) -> Result<ByteSet, ParseError> {
+        let unknown = self.error(ParseErrorReason::UnknownClass);
+
+        let rest = &self.s[self.pos + 2..];
+        let len = rest.find(":]").ok_or(unknown)?;
+        let name = &rest[..len];
+
+        let (_, class) = POSIX_CLASSES
+            .iter()
+            .find(|(class_name, _)| *class_name == name)
+            .ok_or(unknown)?;
+
+        self.pos += 2 + len + 2;
+        Ok(*class)
+    }
+
+    /// Parses an escape sequence starting with `\`.
+    fn escape(&mut self) -> Result<Atom, ParseError> {
+        let invalid = self.error(ParseErrorReason::InvalidEscape);
+        let bytes = self.s.as_bytes();
+
+        let atom = match bytes.get(self.pos + 1) {
+            Some(b'n') => Atom::Byte(b'\n'),
+            Some(b'r') => Atom::Byte(b'\r'),
+            Some(b't') => Atom::Byte(b'\t'),
+            Some(b'f') => Atom::Byte(0x0C),
+            Some(b'v') => Atom::Byte(0x0B),
+            Some(b'd') => Atom::Class(ByteSet::ASCII_DIGIT),
+            Some(b'D') => Atom::Class(ByteSet::ASCII_DIGIT.not()),
+            Some(b's') => Atom::Class(SPACE),
+            Some(b'S') => Atom::Class(SPACE.not()),
+            Some(b'w') => Atom::Class(WORD),
+            Some(b'W') => Atom::Class(WORD.not()),
+            Some(b'x') => {
+                let hex = bytes.get(self.pos + 2..self.pos + 4);
+                let byte = hex.and_then(parse_hex_byte).ok_or(invalid)?;
+
+                self.pos += 4;
+                return Ok(Atom::Byte(byte));
+            }
+            Some(&byte) if byte.is_ascii_punctuation() => Atom::Byte(byte),
+            _ => return Err(invalid),
+        };
+
+        self.pos += 2;
+        Ok(atom)
+    }
+}
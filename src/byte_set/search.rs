@@ -0,0 +1,212 @@
+use super::ByteSet;
+use crate::{
+    searcher::{self, word, Kernel},
+    ByteSearcher,
+};
+use core::iter;
+
+/// Operations for searching byte slices.
+impl ByteSet {
+    /// Returns the index of the first byte in `haystack` that is contained in
+    /// `self`, or `None` if there is none.
+    ///
+    /// This uses the same vector or word-at-a-time kernels as
+    /// [`ByteSearcher::find`], but chooses one on every call. When searching
+    /// many inputs with the same set, create a [`ByteSearcher`] once instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let haystack = b"hello, world";
+    ///
+    /// assert_eq!(ByteSet::ASCII_PUNCTUATION.find_in(haystack), Some(5));
+    /// assert_eq!(ByteSet::ASCII_DIGIT.find_in(haystack), None);
+    /// ```
+    ///
+    /// [`ByteSearcher`]: struct.ByteSearcher.html
+    /// [`ByteSearcher::find`]: struct.ByteSearcher.html#method.find
+    #[inline]
+    #[must_use]
+    pub fn find_in(&self, haystack: &[u8]) -> Option<usize> {
+        if haystack.len() < searcher::MIN_VECTOR_LEN {
+            // Too short for a searcher to pay for its setup.
+            word::find(&Kernel::Table, |b| self.contains(b), haystack)
+        } else {
+            ByteSearcher::new(*self).find(haystack)
+        }
+    }
+
+    /// Returns the index of the last byte in `haystack` that is contained in
+    /// `self`, or `None` if there is none.
+    ///
+    /// This uses the same vector or word-at-a-time kernels as
+    /// [`ByteSearcher::rfind`], but chooses one on every call. When searching
+    /// many inputs with the same set, create a [`ByteSearcher`] once instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let haystack = b"hello, world";
+    ///
+    /// assert_eq!(ByteSet::from(b'o').rfind_in(haystack), Some(8));
+    /// assert_eq!(ByteSet::ASCII_DIGIT.rfind_in(haystack), None);
+    /// ```
+    ///
+    /// [`ByteSearcher`]: struct.ByteSearcher.html
+    /// [`ByteSearcher::rfind`]: struct.ByteSearcher.html#method.rfind
+    #[inline]
+    #[must_use]
+    pub fn rfind_in(&self, haystack: &[u8]) -> Option<usize> {
+        if haystack.len() < searcher::MIN_VECTOR_LEN {
+            // Too short for a searcher to pay for its setup.
+            word::rfind(&Kernel::Table, |b| self.contains(b), haystack)
+        } else {
+            ByteSearcher::new(*self).rfind(haystack)
+        }
+    }
+
+    /// Returns the index of the first byte in `haystack` that is not contained
+    /// in `self`, or `None` if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let haystack = b"   indented";
+    ///
+    /// assert_eq!(ByteSet::ASCII_WHITESPACE.find_not_in(haystack), Some(3));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn find_not_in(&self, haystack: &[u8]) -> Option<usize> {
+        self.not().find_in(haystack)
+    }
+
+    /// Returns the index of the last byte in `haystack` that is not contained
+    /// in `self`, or `None` if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let haystack = b"trailing\r\n";
+    ///
+    /// assert_eq!(ByteSet::ASCII_WHITESPACE.rfind_not_in(haystack), Some(7));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rfind_not_in(&self, haystack: &[u8]) -> Option<usize> {
+        self.not().rfind_in(haystack)
+    }
+
+    /// Returns an iterator over the indices of all bytes in `haystack` that
+    /// are contained in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let haystack = b"a,b;c,d";
+    /// let set = ByteSet::from(&b",;"[..]);
+    ///
+    /// let indices: Vec<usize> = set.find_iter(haystack).collect();
+    /// assert_eq!(indices, [1, 3, 5]);
+    ///
+    /// let indices: Vec<usize> = set.find_iter(haystack).rev().collect();
+    /// assert_eq!(indices, [5, 3, 1]);
+    /// ```
+    #[inline]
+    pub fn find_iter<'h>(&self, haystack: &'h [u8]) -> Positions<'h> {
+        Positions {
+            byte_set: *self,
+            haystack,
+            start: 0,
+            end: haystack.len(),
+        }
+    }
+
+    /// Returns the number of bytes in `haystack` that are contained in `self`.
+    ///
+    /// This uses the same vector or word-at-a-time kernels as
+    /// [`ByteSearcher::count`], but chooses one on every call. When counting
+    /// in many inputs with the same set, create a [`ByteSearcher`] once
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let haystack = b"one\ttwo\r\nthree\n";
+    ///
+    /// assert_eq!(ByteSet::ASCII_CONTROL.count_in(haystack), 4);
+    /// ```
+    ///
+    /// [`ByteSearcher`]: struct.ByteSearcher.html
+    /// [`ByteSearcher::count`]: struct.ByteSearcher.html#method.count
+    #[inline]
+    #[must_use]
+    pub fn count_in(&self, haystack: &[u8]) -> usize {
+        if haystack.len() < searcher::MIN_VECTOR_LEN {
+            // Too short for a searcher to pay for its setup.
+            word::count(&Kernel::Table, |b| self.contains(b), haystack)
+        } else {
+            ByteSearcher::new(*self).count(haystack)
+        }
+    }
+}
+
+/// An iterator over the indices of bytes in a slice that are contained in a
+/// [`ByteSet`].
+///
+/// This is created by [`ByteSet::find_iter`].
+///
+/// [`ByteSet`]: struct.ByteSet.html
+/// [`ByteSet::find_iter`]: struct.ByteSet.html#method.find_iter
+#[derive(Clone, Debug)]
+pub struct Positions<'h> {
+    byte_set: ByteSet,
+    haystack: &'h [u8],
+
+    /// The start of the unsearched part of `haystack`.
+    start: usize,
+
+    /// The end of the unsearched part of `haystack`.
+    end: usize,
+}
+
+impl Iterator for Positions<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let rest = &self.haystack[self.start..self.end];
+        let index = self.start + self.byte_set.find_in(rest)?;
+
+        self.start = index + 1;
+        Some(index)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.end - self.start))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.byte_set.count_in(&self.haystack[self.start..self.end])
+    }
+}
+
+impl DoubleEndedIterator for Positions<'_> {
+    fn next_back(&mut self) -> Option<usize> {
+        let rest = &self.haystack[self.start..self.end];
+        let index = self.start + self.byte_set.rfind_in(rest)?;
+
+        self.end = index;
+        Some(index)
+    }
+}
+
+// `Positions` does not produce more values after `None` is reached.
+impl iter::FusedIterator for Positions<'_> {}
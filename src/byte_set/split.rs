@@ -0,0 +1,419 @@
+use super::ByteSet;
+use core::iter;
+
+/// Operations for splitting byte slices.
+impl ByteSet {
+    /// Returns an iterator over subslices of `haystack` separated by bytes
+    /// contained in `self`.
+    ///
+    /// This behaves like [`slice::split`], so consecutive separators produce
+    /// empty subslices. Use [`tokens`](#method.tokens) to skip them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::{byte_set, ByteSet};
+    /// let set = byte_set![b',', b';'];
+    /// let parts: Vec<&[u8]> = set.split(b"a,b;;c").collect();
+    ///
+    /// assert_eq!(parts, [&b"a"[..], b"b", b"", b"c"]);
+    /// ```
+    ///
+    /// [`slice::split`]: https://doc.rust-lang.org/std/primitive.slice.html#method.split
+    #[inline]
+    pub fn split<'h>(&self, haystack: &'h [u8]) -> Split<'h> {
+        Split {
+            byte_set: *self,
+            haystack,
+            finished: false,
+        }
+    }
+
+    /// Returns an iterator over subslices of `haystack` separated by bytes
+    /// contained in `self`, starting from the end.
+    ///
+    /// This behaves like [`slice::rsplit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::{byte_set, ByteSet};
+    /// let set = byte_set![b'/'];
+    /// let parts: Vec<&[u8]> = set.rsplit(b"usr/local/bin").collect();
+    ///
+    /// assert_eq!(parts, [&b"bin"[..], b"local", b"usr"]);
+    /// ```
+    ///
+    /// [`slice::rsplit`]: https://doc.rust-lang.org/std/primitive.slice.html#method.rsplit
+    #[inline]
+    pub fn rsplit<'h>(&self, haystack: &'h [u8]) -> RSplit<'h> {
+        RSplit {
+            inner: self.split(haystack),
+        }
+    }
+
+    /// Returns an iterator over at most `n` subslices of `haystack` separated
+    /// by bytes contained in `self`. The last subslice contains the remainder
+    /// of `haystack`.
+    ///
+    /// This behaves like [`slice::splitn`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::{byte_set, ByteSet};
+    /// let set = byte_set![b'='];
+    /// let parts: Vec<&[u8]> = set.splitn(2, b"key=a=b").collect();
+    ///
+    /// assert_eq!(parts, [&b"key"[..], b"a=b"]);
+    /// ```
+    ///
+    /// [`slice::splitn`]: https://doc.rust-lang.org/std/primitive.slice.html#method.splitn
+    #[inline]
+    pub fn splitn<'h>(&self, n: usize, haystack: &'h [u8]) -> SplitN<'h> {
+        SplitN {
+            inner: self.split(haystack),
+            remaining: n,
+        }
+    }
+
+    /// Returns an iterator over subslices of `haystack` that each end with a
+    /// byte contained in `self`, except possibly the last.
+    ///
+    /// This behaves like [`slice::split_inclusive`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::{byte_set, ByteSet};
+    /// let set = byte_set![b'\n'];
+    /// let lines: Vec<&[u8]> = set.split_inclusive(b"a\nb\nc").collect();
+    ///
+    /// assert_eq!(lines, [&b"a\n"[..], b"b\n", b"c"]);
+    /// ```
+    ///
+    /// [`slice::split_inclusive`]: https://doc.rust-lang.org/std/primitive.slice.html#method.split_inclusive
+    #[inline]
+    pub fn split_inclusive<'h>(
+        &self,
+        haystack: &'h [u8],
+    ) -> SplitInclusive<'h> {
+        SplitInclusive {
+            byte_set: *self,
+            haystack,
+            finished: haystack.is_empty(),
+        }
+    }
+
+    /// Returns an iterator over the non-empty subslices of `haystack`
+    /// separated by bytes contained in `self`.
+    ///
+    /// This is like [`str::split_whitespace`], but for any set of bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let tokens: Vec<&[u8]> = ByteSet::ASCII_WHITESPACE
+    ///     .tokens(b"  hello \t world\n")
+    ///     .collect();
+    ///
+    /// assert_eq!(tokens, [&b"hello"[..], b"world"]);
+    /// ```
+    ///
+    /// [`str::split_whitespace`]: https://doc.rust-lang.org/std/primitive.str.html#method.split_whitespace
+    #[inline]
+    pub fn tokens<'h>(&self, haystack: &'h [u8]) -> Tokens<'h> {
+        Tokens {
+            byte_set: *self,
+            haystack,
+        }
+    }
+}
+
+/// An iterator over subslices separated by bytes in a [`ByteSet`].
+///
+/// This is created by [`ByteSet::split`].
+///
+/// [`ByteSet`]: struct.ByteSet.html
+/// [`ByteSet::split`]: struct.ByteSet.html#method.split
+#[derive(Clone, Debug)]
+pub struct Split<'h> {
+    byte_set: ByteSet,
+    haystack: &'h [u8],
+    finished: bool,
+}
+
+impl<'h> Split<'h> {
+    /// Returns the rest of the haystack and stops iteration.
+    #[inline]
+    fn finish(&mut self) -> Option<&'h [u8]> {
+        if self.finished {
+            None
+        } else {
+            self.finished = true;
+            Some(self.haystack)
+        }
+    }
+}
+
+impl<'h> Iterator for Split<'h> {
+    type Item = &'h [u8];
+
+    fn next(&mut self) -> Option<&'h [u8]> {
+        if self.finished {
+            return None;
+        }
+
+        match self.byte_set.find_in(self.haystack) {
+            None => self.finish(),
+            Some(index) => {
+                let item = &self.haystack[..index];
+                self.haystack = &self.haystack[(index + 1)..];
+                Some(item)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(self.haystack.len() + 1))
+        }
+    }
+}
+
+impl<'h> DoubleEndedIterator for Split<'h> {
+    fn next_back(&mut self) -> Option<&'h [u8]> {
+        if self.finished {
+            return None;
+        }
+
+        match self.byte_set.rfind_in(self.haystack) {
+            None => self.finish(),
+            Some(index) => {
+                let item = &self.haystack[(index + 1)..];
+                self.haystack = &self.haystack[..index];
+                Some(item)
+            }
+        }
+    }
+}
+
+// `Split` does not produce more values after `None` is reached.
+impl iter::FusedIterator for Split<'_> {}
+
+/// An iterator over subslices separated by bytes in a [`ByteSet`], starting
+/// from the end.
+///
+/// This is created by [`ByteSet::rsplit`].
+///
+/// [`ByteSet`]: struct.ByteSet.html
+/// [`ByteSet::rsplit`]: struct.ByteSet.html#method.rsplit
+#[derive(Clone, Debug)]
+pub struct RSplit<'h> {
+    inner: Split<'h>,
+}
+
+impl<'h> Iterator for RSplit<'h> {
+    type Item = &'h [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'h [u8]> {
+        self.inner.next_back()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'h> DoubleEndedIterator for RSplit<'h> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'h [u8]> {
+        self.inner.next()
+    }
+}
+
+// `RSplit` does not produce more values after `None` is reached.
+impl iter::FusedIterator for RSplit<'_> {}
+
+/// An iterator over at most `n` subslices separated by bytes in a
+/// [`ByteSet`].
+///
+/// This is created by [`ByteSet::splitn`].
+///
+/// [`ByteSet`]: struct.ByteSet.html
+/// [`ByteSet::splitn`]: struct.ByteSet.html#method.splitn
+#[derive(Clone, Debug)]
+pub struct SplitN<'h> {
+    inner: Split<'h>,
+    remaining: usize,
+}
+
+impl<'h> Iterator for SplitN<'h> {
+    type Item = &'h [u8];
+
+    fn next(&mut self) -> Option<&'h [u8]> {
+        match self.remaining {
+            0 => None,
+            1 => {
+                self.remaining = 0;
+                self.inner.finish()
+            }
+            _ => {
+                self.remaining -= 1;
+                self.inner.next()
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        let upper = upper.map(|upper| upper.min(self.remaining));
+
+        (lower.min(self.remaining), upper)
+    }
+}
+
+// `SplitN` does not produce more values after `None` is reached.
+impl iter::FusedIterator for SplitN<'_> {}
+
+/// An iterator over subslices that each end with a byte in a [`ByteSet`],
+/// except possibly the last.
+///
+/// This is created by [`ByteSet::split_inclusive`].
+///
+/// [`ByteSet`]: struct.ByteSet.html
+/// [`ByteSet::split_inclusive`]: struct.ByteSet.html#method.split_inclusive
+#[derive(Clone, Debug)]
+pub struct SplitInclusive<'h> {
+    byte_set: ByteSet,
+    haystack: &'h [u8],
+    finished: bool,
+}
+
+impl<'h> Iterator for SplitInclusive<'h> {
+    type Item = &'h [u8];
+
+    fn next(&mut self) -> Option<&'h [u8]> {
+        if self.finished {
+            return None;
+        }
+
+        let end = match self.byte_set.find_in(self.haystack) {
+            Some(index) => index + 1,
+            None => self.haystack.len(),
+        };
+        if end == self.haystack.len() {
+            self.finished = true;
+        }
+
+        let item = &self.haystack[..end];
+        self.haystack = &self.haystack[end..];
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(self.haystack.len()))
+        }
+    }
+}
+
+impl<'h> DoubleEndedIterator for SplitInclusive<'h> {
+    fn next_back(&mut self) -> Option<&'h [u8]> {
+        if self.finished {
+            return None;
+        }
+
+        // The last byte is included in the last subslice, even if it is a
+        // separator. The haystack is never empty if not finished.
+        let rest = &self.haystack[..(self.haystack.len() - 1)];
+
+        let start = match self.byte_set.rfind_in(rest) {
+            Some(index) => index + 1,
+            None => 0,
+        };
+        if start == 0 {
+            self.finished = true;
+        }
+
+        let item = &self.haystack[start..];
+        self.haystack = &self.haystack[..start];
+        Some(item)
+    }
+}
+
+// `SplitInclusive` does not produce more values after `None` is reached.
+impl iter::FusedIterator for SplitInclusive<'_> {}
+
+/// An iterator over the non-empty subslices separated by bytes in a
+/// [`ByteSet`].
+///
+/// This is created by [`ByteSet::tokens`].
+///
+/// [`ByteSet`]: struct.ByteSet.html
+/// [`ByteSet::tokens`]: struct.ByteSet.html#method.tokens
+#[derive(Clone, Debug)]
+pub struct Tokens<'h> {
+    byte_set: ByteSet,
+    haystack: &'h [u8],
+}
+
+impl<'h> Iterator for Tokens<'h> {
+    type Item = &'h [u8];
+
+    fn next(&mut self) -> Option<&'h [u8]> {
+        let start = match self.byte_set.find_not_in(self.haystack) {
+            Some(start) => start,
+            None => {
+                self.haystack = &[];
+                return None;
+            }
+        };
+        let rest = &self.haystack[start..];
+
+        let end = self.byte_set.find_in(rest).unwrap_or(rest.len());
+
+        self.haystack = &rest[end..];
+        Some(&rest[..end])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Tokens are separated by at least one byte.
+        (0, Some(self.haystack.len() / 2 + 1))
+    }
+}
+
+impl<'h> DoubleEndedIterator for Tokens<'h> {
+    fn next_back(&mut self) -> Option<&'h [u8]> {
+        let end = match self.byte_set.rfind_not_in(self.haystack) {
+            Some(last) => last + 1,
+            None => {
+                self.haystack = &[];
+                return None;
+            }
+        };
+        let rest = &self.haystack[..end];
+
+        let start = match self.byte_set.rfind_in(rest) {
+            Some(index) => index + 1,
+            None => 0,
+        };
+
+        self.haystack = &rest[..start];
+        Some(&rest[start..])
+    }
+}
+
+// `Tokens` does not produce more values after `None` is reached.
+impl iter::FusedIterator for Tokens<'_> {}
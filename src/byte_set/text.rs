@@ -0,0 +1,303 @@
+use super::ByteSet;
+use core::{fmt, str::FromStr};
+
+/// Writes `byte` as a single item of the text syntax.
+fn write_byte(f: &mut fmt::Formatter, byte: u8) -> fmt::Result {
+    match byte {
+        b'\\' => f.write_str("\\\\"),
+        b'-' => f.write_str("\\-"),
+        b'\0' => f.write_str("\\0"),
+        b'\t' => f.write_str("\\t"),
+        b'\n' => f.write_str("\\n"),
+        b'\r' => f.write_str("\\r"),
+        _ if byte.is_ascii_graphic() => fmt::Write::write_char(f, byte as char),
+        _ => write!(f, "\\x{:02x}", byte),
+    }
+}
+
+/// Formats `self` in a text syntax that can be parsed back with [`FromStr`].
+///
+/// Runs of three or more bytes are written as ranges, and bytes that are not
+/// printable ASCII are escaped. See [`from_str`](#method.from_str) for the
+/// syntax.
+///
+/// # Examples
+///
+/// ```
+/// # use byte_set::ByteSet;
+/// let set = ByteSet::ASCII_ALPHANUMERIC.inserting(b'_').inserting(0);
+///
+/// assert_eq!(set.to_string(), r"\0 0-9 A-Z _ a-z");
+/// assert_eq!(set.to_string().parse(), Ok(set));
+/// ```
+///
+/// [`FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
+impl fmt::Display for ByteSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, range) in self.ranges().enumerate() {
+            if i != 0 {
+                f.write_str(" ")?;
+            }
+
+            let (start, end) = range.into_inner();
+            write_byte(f, start)?;
+
+            match end - start {
+                0 => {}
+                1 => {
+                    f.write_str(" ")?;
+                    write_byte(f, end)?;
+                }
+                _ => {
+                    f.write_str("-")?;
+                    write_byte(f, end)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses a set from its text syntax.
+///
+/// The syntax is a sequence of items, where each item is either a single byte
+/// or an inclusive range of bytes written as `start-end`. Whitespace between
+/// items is optional. An empty string is an empty set.
+///
+/// Printable ASCII characters stand for themselves, except for `\` and `-`,
+/// which must be written as `\\` and `\-`. Other bytes are written as `\0`,
+/// `\t`, `\n`, `\r`, or `\xHH` in hexadecimal.
+///
+/// # Examples
+///
+/// ```
+/// # use byte_set::ByteSet;
+/// let set: ByteSet = r"a-z A-Z 0-9 _ \- \x00-\x1f".parse().unwrap();
+///
+/// assert!(set.contains(b'q'));
+/// assert!(set.contains(b'-'));
+/// assert!(set.contains(b'\n'));
+/// assert_eq!(set.len(), 26 * 2 + 10 + 2 + 32);
+///
+/// let error = "a-z 9-0".parse::<ByteSet>().unwrap_err();
+/// assert_eq!(error.column(), 5);
+/// ```
+impl FromStr for ByteSet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        Parser { s, pos: 0 }.parse()
+    }
+}
+
+/// Parses exactly two hexadecimal digits as a byte.
+pub(super) fn parse_hex_byte(hex: &[u8]) -> Option<u8> {
+    fn digit(byte: u8) -> Option<u8> {
+        (byte as char).to_digit(16).map(|digit| digit as u8)
+    }
+
+    match *hex {
+        [high, low] => Some(digit(high)? << 4 | digit(low)?),
+        _ => None,
+    }
+}
+
+/// Parses the text syntax of a [`ByteSet`].
+///
+/// [`ByteSet`]: struct.ByteSet.html
+struct Parser<'a> {
+    s: &'a str,
+    /// The byte offset into `s` of the next character to parse.
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn parse(&mut self) -> Result<ByteSet, ParseError> {
+        let mut set = ByteSet::new();
+
+        loop {
+            while matches!(self.peek(), Some(b) if b.is_ascii_whitespace()) {
+                self.pos += 1;
+            }
+
+            let start_pos = self.pos;
+            let start = match self.peek() {
+                Some(_) => self.byte()?,
+                None => return Ok(set),
+            };
+
+            if self.peek() != Some(b'-') {
+                set.insert(start);
+                continue;
+            }
+
+            self.pos += 1;
+            match self.peek() {
+                Some(b) if !b.is_ascii_whitespace() => {}
+                _ => return Err(self.error(ParseErrorReason::MissingRangeEnd)),
+            }
+
+            let end = self.byte()?;
+            if end < start {
+                let reason = ParseErrorReason::DecreasingRange { start, end };
+                return Err(ParseError::new(start_pos + 1, reason));
+            }
+
+            set.insert_all(ByteSet::from_range_inclusive(start..=end));
+        }
+    }
+
+    #[inline]
+    fn peek(&self) -> Option<u8> {
+        self.s.as_bytes().get(self.pos).cloned()
+    }
+
+    /// Returns an error for the character at the current position.
+    #[inline]
+    fn error(&self, reason: ParseErrorReason) -> ParseError {
+        ParseError::new(self.pos + 1, reason)
+    }
+
+    /// Parses a single byte, which is either a printable ASCII character or an
+    /// escape sequence.
+    fn byte(&mut self) -> Result<u8, ParseError> {
+        let byte = match self.peek() {
+            Some(byte) => byte,
+            None => return Err(self.error(ParseErrorReason::MissingRangeEnd)),
+        };
+
+        if byte == b'\\' {
+            return self.escape();
+        }
+
+        if byte == b'-' || !byte.is_ascii_graphic() {
+            // Everything before `pos` is ASCII, so this is a `char` boundary.
+            let c = self.s[self.pos..].chars().next().unwrap_or_default();
+            return Err(self.error(ParseErrorReason::UnexpectedChar(c)));
+        }
+
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    /// Parses an escape sequence starting with `\`.
+    fn escape(&mut self) -> Result<u8, ParseError> {
+        let invalid = self.error(ParseErrorReason::InvalidEscape);
+        let bytes = self.s.as_bytes();
+
+        let byte = match bytes.get(self.pos + 1) {
+            Some(b'\\') => b'\\',
+            Some(b'-') => b'-',
+            Some(b'0') => b'\0',
+            Some(b't') => b'\t',
+            Some(b'n') => b'\n',
+            Some(b'r') => b'\r',
+            Some(b'x') => {
+                let hex = bytes.get(self.pos + 2..self.pos + 4);
+                let byte = hex.and_then(parse_hex_byte).ok_or(invalid)?;
+
+                self.pos += 4;
+                return Ok(byte);
+            }
+            _ => return Err(invalid),
+        };
+
+        self.pos += 2;
+        Ok(byte)
+    }
+}
+
+/// The error returned when parsing a [`ByteSet`] from text fails.
+///
+/// This is returned by [`from_str`] and [`ByteSet::from_regex_class`].
+///
+/// [`ByteSet`]: struct.ByteSet.html
+/// [`from_str`]: struct.ByteSet.html#method.from_str
+/// [`ByteSet::from_regex_class`]: struct.ByteSet.html#method.from_regex_class
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    column: usize,
+    reason: ParseErrorReason,
+}
+
+impl ParseError {
+    #[inline]
+    pub(super) const fn new(column: usize, reason: ParseErrorReason) -> Self {
+        Self { column, reason }
+    }
+
+    /// Returns the column of the input where the error occurred, starting at 1.
+    #[inline]
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the reason that parsing failed.
+    #[inline]
+    pub const fn reason(&self) -> ParseErrorReason {
+        self.reason
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at column {}", self.reason, self.column)
+    }
+}
+
+#[cfg(any(test, feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for ParseError {}
+
+/// The reason that parsing a [`ByteSet`] from text failed.
+///
+/// [`ByteSet`]: struct.ByteSet.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseErrorReason {
+    /// A character that must be escaped, or that is not ASCII.
+    UnexpectedChar(char),
+    /// A `\` that does not start a valid escape sequence.
+    InvalidEscape,
+    /// A `-` that is not followed by the end of a range.
+    MissingRangeEnd,
+    /// A range whose end is less than its start.
+    DecreasingRange {
+        /// The start of the range.
+        start: u8,
+        /// The end of the range.
+        end: u8,
+    },
+    /// A regex character class that does not start with `[`.
+    MissingOpenBracket,
+    /// A regex character class that is not closed by `]`.
+    MissingCloseBracket,
+    /// A POSIX class such as `[:alpha:]` with an unknown name.
+    UnknownClass,
+    /// A class such as `\d` or `[:alpha:]` used as the start or end of a
+    /// range.
+    ClassInRange,
+}
+
+impl fmt::Display for ParseErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::UnexpectedChar(c) => {
+                write!(f, "unexpected character {:?}", c)
+            }
+            Self::InvalidEscape => f.write_str("invalid escape sequence"),
+            Self::MissingRangeEnd => f.write_str("missing end of range"),
+            Self::DecreasingRange { start, end } => write!(
+                f,
+                "range end {:#04X} is less than start {:#04X}",
+                end, start
+            ),
+            Self::MissingOpenBracket => f.write_str("expected `[`"),
+            Self::MissingCloseBracket => f.write_str("unclosed `[`"),
+            Self::UnknownClass => f.write_str("unknown character class"),
+            Self::ClassInRange => {
+                f.write_str("character class cannot be part of a range")
+            }
+        }
+    }
+}
@@ -1,6 +1,6 @@
-use super::ByteSet;
-use crate::Iter;
-use core::{cmp, fmt, hash, iter::FromIterator, ops};
+use super::{vector, ByteSet};
+use crate::{chunk, Chunk, Iter};
+use core::{cmp, fmt, hash, iter::FromIterator, ops, str};
 
 #[cfg(any(test, feature = "std"))]
 use std::collections::HashSet;
@@ -27,9 +27,7 @@ impl From<u8> for ByteSet {
 impl From<&[u8]> for ByteSet {
     #[inline]
     fn from(bytes: &[u8]) -> Self {
-        let mut set = ByteSet::new();
-        set.extend(bytes);
-        set
+        Self::from_slice(bytes)
     }
 }
 
@@ -134,9 +132,228 @@ impl IntoIterator for ByteSet {
     }
 }
 
+/// Formats a byte as a byte literal if it is printable ASCII or has a common
+/// escape, or in hexadecimal otherwise.
+struct DebugByte(u8);
+
+impl fmt::Debug for DebugByte {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            b'\\' => f.write_str("b'\\\\'"),
+            b'\'' => f.write_str("b'\\''"),
+            b'\0' => f.write_str("b'\\0'"),
+            b'\t' => f.write_str("b'\\t'"),
+            b'\n' => f.write_str("b'\\n'"),
+            b'\r' => f.write_str("b'\\r'"),
+            byte if byte == b' ' || byte.is_ascii_graphic() => {
+                write!(f, "b'{}'", byte as char)
+            }
+            byte => write!(f, "{:#04X}", byte),
+        }
+    }
+}
+
+/// Formats a run of bytes as a single byte or an inclusive range.
+struct DebugRun(ops::RangeInclusive<u8>);
+
+impl fmt::Debug for DebugRun {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (start, end) = (*self.0.start(), *self.0.end());
+
+        DebugByte(start).fmt(f)?;
+        if start != end {
+            f.write_str("..=")?;
+            DebugByte(end).fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats an identifier without quotes.
+struct DebugName(&'static str);
+
+impl fmt::Debug for DebugName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+/// The ASCII classes listed by the alternate `Debug` format, from largest to
+/// smallest.
+const ASCII_CLASSES: [(&str, ByteSet); 11] = [
+    ("ASCII", ByteSet::ASCII),
+    ("ASCII_GRAPHIC", ByteSet::ASCII_GRAPHIC),
+    ("ASCII_ALPHANUMERIC", ByteSet::ASCII_ALPHANUMERIC),
+    ("ASCII_ALPHABETIC", ByteSet::ASCII_ALPHABETIC),
+    ("ASCII_PUNCTUATION", ByteSet::ASCII_PUNCTUATION),
+    ("ASCII_CONTROL", ByteSet::ASCII_CONTROL),
+    ("ASCII_HEXDIGIT", ByteSet::ASCII_HEXDIGIT),
+    ("ASCII_UPPERCASE", ByteSet::ASCII_UPPERCASE),
+    ("ASCII_LOWERCASE", ByteSet::ASCII_LOWERCASE),
+    ("ASCII_DIGIT", ByteSet::ASCII_DIGIT),
+    ("ASCII_WHITESPACE", ByteSet::ASCII_WHITESPACE),
+];
+
+/// Formats the contiguous runs of bytes in `self`, such as
+/// `{b'0'..=b'9', b'A'..=b'Z', 0x80..=0xFF}`.
+///
+/// Printable ASCII bytes are written as byte literals, and other bytes are
+/// written in hexadecimal.
+///
+/// The alternate format (`{:#?}`) also lists the predefined ASCII classes
+/// that `self` fully contains, omitting those implied by a larger listed
+/// class.
+///
+/// # Examples
+///
+/// ```
+/// # use byte_set::ByteSet;
+/// let set = ByteSet::ASCII_ALPHANUMERIC.inserting(b'_');
+///
+/// assert_eq!(
+///     format!("{:?}", set),
+///     "{b'0'..=b'9', b'A'..=b'Z', b'_', b'a'..=b'z'}",
+/// );
+/// ```
 impl fmt::Debug for ByteSet {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_set().entries(*self).finish()
+        struct Runs(ByteSet);
+
+        impl fmt::Debug for Runs {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_set()
+                    .entries(self.0.ranges().map(DebugRun))
+                    .finish()
+            }
+        }
+
+        struct Classes(ByteSet);
+
+        impl fmt::Debug for Classes {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let mut listed = ByteSet::new();
+                let mut list = f.debug_list();
+
+                for &(name, class) in &ASCII_CLASSES {
+                    // Classes are ordered so that supersets are listed first.
+                    if class.is_subset(&self.0) && !class.is_subset(&listed) {
+                        listed.insert_all(class);
+                        list.entry(&DebugName(name));
+                    }
+                }
+                list.finish()
+            }
+        }
+
+        if f.alternate() {
+            f.debug_struct("ByteSet")
+                .field("ranges", &Runs(*self))
+                .field("ascii_classes", &Classes(*self))
+                .finish()
+        } else {
+            Runs(*self).fmt(f)
+        }
+    }
+}
+
+impl ByteSet {
+    /// Formats `self` as a 256-bit unsigned integer where bit `n` is set if
+    /// `n` is contained, using `bits` bits per digit.
+    ///
+    /// Digits are read from chunks by bit position, so the output is the same
+    /// regardless of chunk width.
+    fn fmt_radix(
+        &self,
+        f: &mut fmt::Formatter,
+        bits: usize,
+        prefix: &str,
+        digits: &[u8; 16],
+    ) -> fmt::Result {
+        let mut buf = [0u8; 256];
+        let mut start = buf.len();
+
+        let mask: Chunk = (1 << bits) - 1;
+        for bit in (0..256).step_by(bits) {
+            let chunk = self.0[bit / chunk::INDEX_OFFSET];
+            let digit = (chunk >> (bit % chunk::INDEX_OFFSET)) & mask;
+
+            start -= 1;
+            buf[start] = digits[digit as usize];
+        }
+
+        // Like integers, there are no leading zeros unless the value is 0.
+        let first = buf[start..]
+            .iter()
+            .position(|&digit| digit != b'0')
+            .map_or(buf.len() - 1, |i| start + i);
+
+        // SAFETY: All digits are ASCII.
+        let digits = unsafe { str::from_utf8_unchecked(&buf[first..]) };
+        f.pad_integral(true, prefix, digits)
+    }
+}
+
+/// Formats `self` as a 256-bit binary number where bit `n` is set if `n` is
+/// contained.
+///
+/// This supports the same flags as integers, including `#`, `0`, width, fill,
+/// and alignment.
+///
+/// # Examples
+///
+/// ```
+/// # use byte_set::ByteSet;
+/// let set = ByteSet::from(&[0, 2, 3][..]);
+///
+/// assert_eq!(format!("{:b}", set), "1101");
+/// assert_eq!(format!("{:#010b}", set), "0b00001101");
+/// ```
+impl fmt::Binary for ByteSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_radix(f, 1, "0b", b"0123456789abcdef")
+    }
+}
+
+/// Formats `self` as a 256-bit hexadecimal number where bit `n` is set if `n`
+/// is contained.
+///
+/// This supports the same flags as integers, including `#`, `0`, width, fill,
+/// and alignment.
+///
+/// # Examples
+///
+/// ```
+/// # use byte_set::ByteSet;
+/// let set = ByteSet::from(&[0, 2, 3, 255][..]);
+///
+/// assert_eq!(format!("{:#x}", ByteSet::ASCII_DIGIT), "0x3ff000000000000");
+/// assert!(format!("{:x}", set).starts_with("8000"));
+/// assert!(format!("{:x}", set).ends_with("000d"));
+/// ```
+impl fmt::LowerHex for ByteSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_radix(f, 4, "0x", b"0123456789abcdef")
+    }
+}
+
+/// Formats `self` as a 256-bit hexadecimal number where bit `n` is set if `n`
+/// is contained, with uppercase digits.
+///
+/// This supports the same flags as integers, including `#`, `0`, width, fill,
+/// and alignment.
+///
+/// # Examples
+///
+/// ```
+/// # use byte_set::ByteSet;
+/// let set = ByteSet::from(&[0, 2, 3][..]);
+///
+/// assert_eq!(format!("{:>6X}", set), "     D");
+/// assert_eq!(format!("{:#X}", ByteSet::ASCII_DIGIT), "0x3FF000000000000");
+/// ```
+impl fmt::UpperHex for ByteSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_radix(f, 4, "0x", b"0123456789ABCDEF")
     }
 }
 
@@ -147,16 +364,60 @@ impl PartialOrd for ByteSet {
     }
 }
 
+/// Compares the bytes of each set in ascending order lexicographically, the same
+/// as [`BTreeSet<u8>`].
+///
+/// This is computed directly from the chunks of each set, without iterating
+/// over their bytes. For other orders, see [`cmp_by_len`] and [`cmp_colex`].
+///
+/// # Examples
+///
+/// ```
+/// # use byte_set::ByteSet;
+/// let ab = ByteSet::from(&b"ab"[..]);
+/// let abc = ByteSet::from(&b"abc"[..]);
+/// let ac = ByteSet::from(&b"ac"[..]);
+///
+/// assert!(ab < abc);
+/// assert!(abc < ac);
+/// ```
+///
+/// [`BTreeSet<u8>`]: https://doc.rust-lang.org/std/collections/struct.BTreeSet.html
+/// [`cmp_by_len`]:   struct.ByteSet.html#method.cmp_by_len
+/// [`cmp_colex`]:    struct.ByteSet.html#method.cmp_colex
 impl Ord for ByteSet {
     #[inline]
     fn cmp(&self, other: &Self) -> cmp::Ordering {
-        // TODO: Optimize using internal representation while keeping semantics.
-        // See https://github.com/nvzqz/byte-set-rs/issues/9.
-        self.into_iter().cmp(other.into_iter())
+        // The lowest byte in only one of the sets decides the order. Bytes
+        // before it are the same in both, so the set with that byte is less if
+        // the other set continues after it, and greater if the other set ends
+        // before it.
+        for i in 0..Self::NUM_SLOTS {
+            let (a, b) = (self.0[i], other.0[i]);
+
+            let diff = a ^ b;
+            if diff == 0 {
+                continue;
+            }
+
+            let shift = diff.trailing_zeros();
+            let self_has_byte = a & (1 << shift) != 0;
+
+            let without = if self_has_byte { other } else { self };
+            let has_greater = (without.0[i] >> shift >> 1) != 0
+                || without.0[i + 1..].iter().any(|&chunk| chunk != 0);
+
+            return if self_has_byte == has_greater {
+                cmp::Ordering::Less
+            } else {
+                cmp::Ordering::Greater
+            };
+        }
+        cmp::Ordering::Equal
     }
 }
 
-#[allow(clippy::derive_hash_xor_eq)]
+#[allow(clippy::derived_hash_with_manual_eq)]
 impl hash::Hash for ByteSet {
     #[inline]
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
@@ -174,7 +435,7 @@ impl ops::Sub for ByteSet {
 
     #[inline]
     fn sub(self, rhs: Self) -> Self::Output {
-        self.removing_all(rhs)
+        vector::difference(&self, &rhs)
     }
 }
 
@@ -190,7 +451,7 @@ impl ops::BitAnd for ByteSet {
 
     #[inline]
     fn bitand(self, rhs: Self) -> Self::Output {
-        self.intersection(rhs)
+        vector::intersection(&self, &rhs)
     }
 }
 
@@ -206,7 +467,7 @@ impl ops::BitOr for ByteSet {
 
     #[inline]
     fn bitor(self, rhs: Self) -> Self::Output {
-        self.inserting_all(rhs)
+        vector::union(&self, &rhs)
     }
 }
 
@@ -222,7 +483,7 @@ impl ops::BitXor for ByteSet {
 
     #[inline]
     fn bitxor(self, rhs: Self) -> Self::Output {
-        self.symmetric_difference(rhs)
+        vector::symmetric_difference(&self, &rhs)
     }
 }
 
@@ -238,7 +499,7 @@ impl ops::Not for ByteSet {
 
     #[inline]
     fn not(self) -> Self::Output {
-        ByteSet::not(self)
+        vector::not(&self)
     }
 }
 
@@ -293,6 +554,11 @@ impl rand::distributions::Distribution<ByteSet>
     }
 }
 
+/// Serializes with [`serde::text`] in human-readable formats and
+/// [`serde::bitmap`] otherwise.
+///
+/// [`serde::text`]:   serde/text/index.html
+/// [`serde::bitmap`]: serde/bitmap/index.html
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 impl serde::Serialize for ByteSet {
@@ -300,16 +566,24 @@ impl serde::Serialize for ByteSet {
     where
         S: serde::Serializer,
     {
-        use serde::ser::SerializeSeq;
-
-        let mut seq = serializer.serialize_seq(Some(self.len()))?;
-        for byte in *self {
-            seq.serialize_element(&byte)?;
+        if serializer.is_human_readable() {
+            crate::serde::text::serialize(self, serializer)
+        } else {
+            crate::serde::bitmap::serialize(self, serializer)
         }
-        seq.end()
     }
 }
 
+/// Deserializes any of the [`serde`] representations in self-describing
+/// formats, and [`serde::bitmap`] otherwise.
+///
+/// In self-describing formats, bytes and sequences of integers are read as the
+/// bytes in the set, as they were before the [`serde`] module was added. Binary
+/// data written before then can be read with [`serde::seq`].
+///
+/// [`serde`]:         serde/index.html
+/// [`serde::seq`]:    serde/seq/index.html
+/// [`serde::bitmap`]: serde/bitmap/index.html
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 impl<'de> serde::Deserialize<'de> for ByteSet {
@@ -317,34 +591,11 @@ impl<'de> serde::Deserialize<'de> for ByteSet {
     where
         D: serde::Deserializer<'de>,
     {
-        struct ByteSetVisitor;
-
-        impl<'de> serde::de::Visitor<'de> for ByteSetVisitor {
-            type Value = ByteSet;
-
-            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                f.write_str("a set of bytes")
-            }
-
-            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                Ok(v.into())
-            }
-
-            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-            where
-                A: serde::de::SeqAccess<'de>,
-            {
-                let mut set = ByteSet::new();
-                while let Some(byte) = seq.next_element::<u8>()? {
-                    set.insert(byte);
-                }
-                Ok(set)
-            }
-        }
-
-        deserializer.deserialize_seq(ByteSetVisitor)
+        let form = if deserializer.is_human_readable() {
+            crate::serde::Form::Text
+        } else {
+            crate::serde::Form::Bitmap
+        };
+        crate::serde::deserialize(deserializer, form)
     }
 }
@@ -0,0 +1,125 @@
+use super::ByteSet;
+use core::str;
+
+/// Operations for trimming byte slices and strings.
+impl ByteSet {
+    /// Returns `haystack` with all leading and trailing bytes contained in
+    /// `self` removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let trimmed = ByteSet::ASCII_WHITESPACE.trim(b"\t hello world\r\n");
+    ///
+    /// assert_eq!(trimmed, b"hello world");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn trim<'h>(&self, haystack: &'h [u8]) -> &'h [u8] {
+        self.trim_end(self.trim_start(haystack))
+    }
+
+    /// Returns `haystack` with all leading bytes contained in `self` removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let trimmed = ByteSet::ASCII_DIGIT.trim_start(b"0042 apples 7");
+    ///
+    /// assert_eq!(trimmed, b" apples 7");
+    /// assert_eq!(ByteSet::full().trim_start(b"anything"), b"");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn trim_start<'h>(&self, haystack: &'h [u8]) -> &'h [u8] {
+        match self.find_not_in(haystack) {
+            Some(start) => &haystack[start..],
+            None => &[],
+        }
+    }
+
+    /// Returns `haystack` with all trailing bytes contained in `self` removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let trimmed = ByteSet::from(&b"\r\n"[..]).trim_end(b"line\r\n\r\n");
+    ///
+    /// assert_eq!(trimmed, b"line");
+    /// assert_eq!(ByteSet::new().trim_end(b"line\n"), b"line\n");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn trim_end<'h>(&self, haystack: &'h [u8]) -> &'h [u8] {
+        match self.rfind_not_in(haystack) {
+            Some(last) => &haystack[..=last],
+            None => &[],
+        }
+    }
+
+    /// Returns `s` with all leading and trailing bytes contained in `self`
+    /// removed, or `None` if `self` is not [ASCII](#method.is_ascii).
+    ///
+    /// Requiring `self` to be ASCII guarantees that the result is valid UTF-8
+    /// without checking it again. ASCII bytes never appear within multi-byte
+    /// UTF-8 sequences, so removing them can never split a `char`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let set = ByteSet::ASCII_WHITESPACE;
+    ///
+    /// assert_eq!(set.trim_str("  héllo wörld\n"), Some("héllo wörld"));
+    ///
+    /// // Trimming with non-ASCII bytes could produce invalid UTF-8.
+    /// assert_eq!(ByteSet::full().trim_str("é"), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn trim_str<'h>(&self, s: &'h str) -> Option<&'h str> {
+        self.map_ascii_str(s, |haystack| self.trim(haystack))
+    }
+
+    /// Returns `s` with all leading bytes contained in `self` removed, or
+    /// `None` if `self` is not [ASCII](#method.is_ascii).
+    ///
+    /// See [`trim_str`](#method.trim_str) for why `self` must be ASCII.
+    #[inline]
+    #[must_use]
+    pub fn trim_start_str<'h>(&self, s: &'h str) -> Option<&'h str> {
+        self.map_ascii_str(s, |haystack| self.trim_start(haystack))
+    }
+
+    /// Returns `s` with all trailing bytes contained in `self` removed, or
+    /// `None` if `self` is not [ASCII](#method.is_ascii).
+    ///
+    /// See [`trim_str`](#method.trim_str) for why `self` must be ASCII.
+    #[inline]
+    #[must_use]
+    pub fn trim_end_str<'h>(&self, s: &'h str) -> Option<&'h str> {
+        self.map_ascii_str(s, |haystack| self.trim_end(haystack))
+    }
+
+    /// Applies `f` to the bytes of `s` if `self` is ASCII, and returns the
+    /// result as a `str`.
+    ///
+    /// `f` must only remove bytes contained in `self` from the start or end of
+    /// its input.
+    #[inline]
+    fn map_ascii_str<'h, F>(&self, s: &'h str, f: F) -> Option<&'h str>
+    where
+        F: FnOnce(&'h [u8]) -> &'h [u8],
+    {
+        if !self.is_ascii() {
+            return None;
+        }
+
+        // SAFETY: Only ASCII bytes were removed from the ends of valid UTF-8,
+        // which is always on a `char` boundary.
+        Some(unsafe { str::from_utf8_unchecked(f(s.as_bytes())) })
+    }
+}
@@ -0,0 +1,92 @@
+use super::ByteSet;
+use core::fmt;
+
+/// Operations for validating byte slices.
+impl ByteSet {
+    /// Returns `Ok` if all bytes in `input` are contained in `self`, or the
+    /// first byte that is not.
+    ///
+    /// This searches a word or vector at a time, like
+    /// [`find_not_in`](#method.find_not_in).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteSet;
+    /// let ident = ByteSet::ASCII_ALPHANUMERIC.inserting(b'_');
+    ///
+    /// assert!(ident.validate(b"snake_case_42").is_ok());
+    ///
+    /// let error = ident.validate(b"kebab-case").unwrap_err();
+    /// assert_eq!(error.position(), 5);
+    /// assert_eq!(error.byte(), b'-');
+    /// ```
+    #[inline]
+    pub fn validate(&self, input: &[u8]) -> Result<(), InvalidByte> {
+        match self.find_not_in(input) {
+            None => Ok(()),
+            Some(position) => Err(InvalidByte {
+                position,
+                byte: input[position],
+            }),
+        }
+    }
+
+    /// Returns `true` if all bytes in `input` are contained in `self`.
+    ///
+    /// This is `true` if `input` is empty. It searches a word or vector at a
+    /// time, like [`find_not_in`](#method.find_not_in).
+    #[inline]
+    #[must_use]
+    pub fn all_in(&self, input: &[u8]) -> bool {
+        self.find_not_in(input).is_none()
+    }
+
+    /// Returns `true` if any byte in `input` is contained in `self`.
+    ///
+    /// This is `false` if `input` is empty. It searches a word or vector at a
+    /// time, like [`find_in`](#method.find_in).
+    #[inline]
+    #[must_use]
+    pub fn any_in(&self, input: &[u8]) -> bool {
+        self.find_in(input).is_some()
+    }
+}
+
+/// The error returned by [`ByteSet::validate`] for a byte that is not in the
+/// set.
+///
+/// [`ByteSet::validate`]: struct.ByteSet.html#method.validate
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InvalidByte {
+    position: usize,
+    byte: u8,
+}
+
+impl InvalidByte {
+    /// Returns the index of the invalid byte in the input.
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the invalid byte.
+    #[inline]
+    pub const fn byte(&self) -> u8 {
+        self.byte
+    }
+}
+
+impl fmt::Display for InvalidByte {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid byte {:#04X} at position {}",
+            self.byte, self.position
+        )
+    }
+}
+
+#[cfg(any(test, feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for InvalidByte {}
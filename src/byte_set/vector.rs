@@ -0,0 +1,456 @@
+//! Whole-set operations written with explicit vector instructions.
+//!
+//! A [`ByteSet`] is reinterpreted as an array of the widest vector type whose
+//! target features are enabled at compile time:
+//!
+//! | Target             | Feature   | Vector       | Per set |
+//! | ------------------ | --------- | ------------ | ------- |
+//! | `x86` and `x86_64` | `avx2`    | `__m256i`    | 1       |
+//! | `x86` and `x86_64` | `sse2`    | `__m128i`    | 2       |
+//! | `aarch64`          | `neon`    | `uint8x16_t` | 2       |
+//! | `wasm32`           | `simd128` | `v128`       | 2       |
+//! | Anything else      |           | `Chunk`      | 4 or 8  |
+//!
+//! Each operation is written once over that array, so adding a backend only
+//! means implementing `Lane` for its vector type.
+//!
+//! `const fn`s cannot call intrinsics, so the `const` methods of [`ByteSet`]
+//! use the portable loops in `macros.rs` instead. The functions here back the
+//! operator traits and the methods that are not `const`.
+//!
+//! [`ByteSet`]: ../struct.ByteSet.html
+
+use super::ByteSet;
+use core::mem;
+
+/// Operations on the vector type that whole-set operations are built from.
+///
+/// The methods are `unsafe` because they use instructions that must be
+/// supported by the CPU. Each implementation is only compiled when its target
+/// features are enabled, so calling them is always sound.
+trait Lane: Copy {
+    unsafe fn or(self, other: Self) -> Self;
+
+    unsafe fn and(self, other: Self) -> Self;
+
+    /// Returns `self & !other`.
+    unsafe fn and_not(self, other: Self) -> Self;
+
+    unsafe fn xor(self, other: Self) -> Self;
+
+    unsafe fn not(self) -> Self;
+
+    unsafe fn is_zero(self) -> bool;
+
+    unsafe fn count_ones(self) -> usize;
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx2",
+))]
+mod avx2 {
+    use super::Lane;
+    use core::mem;
+
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    pub type Vector = __m256i;
+
+    impl Lane for __m256i {
+        #[inline(always)]
+        unsafe fn or(self, other: Self) -> Self {
+            _mm256_or_si256(self, other)
+        }
+
+        #[inline(always)]
+        unsafe fn and(self, other: Self) -> Self {
+            _mm256_and_si256(self, other)
+        }
+
+        #[inline(always)]
+        unsafe fn and_not(self, other: Self) -> Self {
+            _mm256_andnot_si256(other, self)
+        }
+
+        #[inline(always)]
+        unsafe fn xor(self, other: Self) -> Self {
+            _mm256_xor_si256(self, other)
+        }
+
+        #[inline(always)]
+        unsafe fn not(self) -> Self {
+            _mm256_xor_si256(self, _mm256_set1_epi8(-1))
+        }
+
+        #[inline(always)]
+        unsafe fn is_zero(self) -> bool {
+            _mm256_testz_si256(self, self) != 0
+        }
+
+        #[inline(always)]
+        unsafe fn count_ones(self) -> usize {
+            // Look up the popcount of each nibble, then sum the bytes of each
+            // 64-bit lane.
+            #[rustfmt::skip]
+            let table = _mm256_setr_epi8(
+                0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+                0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+            );
+            let nibble = _mm256_set1_epi8(0xF);
+
+            let low = _mm256_and_si256(self, nibble);
+            let high = _mm256_and_si256(_mm256_srli_epi16::<4>(self), nibble);
+            let counts = _mm256_add_epi8(
+                _mm256_shuffle_epi8(table, low),
+                _mm256_shuffle_epi8(table, high),
+            );
+
+            let sums = _mm256_sad_epu8(counts, _mm256_setzero_si256());
+            let sums: [u64; 4] = mem::transmute(sums);
+            sums.iter().sum::<u64>() as usize
+        }
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2",
+    not(target_feature = "avx2"),
+))]
+mod sse2 {
+    use super::Lane;
+    use core::mem;
+
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    pub type Vector = __m128i;
+
+    impl Lane for __m128i {
+        #[inline(always)]
+        unsafe fn or(self, other: Self) -> Self {
+            _mm_or_si128(self, other)
+        }
+
+        #[inline(always)]
+        unsafe fn and(self, other: Self) -> Self {
+            _mm_and_si128(self, other)
+        }
+
+        #[inline(always)]
+        unsafe fn and_not(self, other: Self) -> Self {
+            _mm_andnot_si128(other, self)
+        }
+
+        #[inline(always)]
+        unsafe fn xor(self, other: Self) -> Self {
+            _mm_xor_si128(self, other)
+        }
+
+        #[inline(always)]
+        unsafe fn not(self) -> Self {
+            _mm_xor_si128(self, _mm_set1_epi8(-1))
+        }
+
+        #[inline(always)]
+        unsafe fn is_zero(self) -> bool {
+            let is_zero = _mm_cmpeq_epi8(self, _mm_setzero_si128());
+            _mm_movemask_epi8(is_zero) == 0xFFFF
+        }
+
+        #[inline(always)]
+        unsafe fn count_ones(self) -> usize {
+            // SSE2 has no byte shuffle to look up nibble counts with, and
+            // `popcnt` on each half is just as fast.
+            let [low, high]: [u64; 2] = mem::transmute(self);
+            (low.count_ones() + high.count_ones()) as usize
+        }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod neon {
+    use super::Lane;
+    use core::arch::aarch64::*;
+
+    pub type Vector = uint8x16_t;
+
+    impl Lane for uint8x16_t {
+        #[inline(always)]
+        unsafe fn or(self, other: Self) -> Self {
+            vorrq_u8(self, other)
+        }
+
+        #[inline(always)]
+        unsafe fn and(self, other: Self) -> Self {
+            vandq_u8(self, other)
+        }
+
+        #[inline(always)]
+        unsafe fn and_not(self, other: Self) -> Self {
+            vbicq_u8(self, other)
+        }
+
+        #[inline(always)]
+        unsafe fn xor(self, other: Self) -> Self {
+            veorq_u8(self, other)
+        }
+
+        #[inline(always)]
+        unsafe fn not(self) -> Self {
+            vmvnq_u8(self)
+        }
+
+        #[inline(always)]
+        unsafe fn is_zero(self) -> bool {
+            vmaxvq_u8(self) == 0
+        }
+
+        #[inline(always)]
+        unsafe fn count_ones(self) -> usize {
+            // At most 128, so the sum of byte counts fits in a byte.
+            vaddvq_u8(vcntq_u8(self)) as usize
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod simd128 {
+    use super::Lane;
+    use core::{arch::wasm32::*, mem};
+
+    pub type Vector = v128;
+
+    impl Lane for v128 {
+        #[inline(always)]
+        unsafe fn or(self, other: Self) -> Self {
+            v128_or(self, other)
+        }
+
+        #[inline(always)]
+        unsafe fn and(self, other: Self) -> Self {
+            v128_and(self, other)
+        }
+
+        #[inline(always)]
+        unsafe fn and_not(self, other: Self) -> Self {
+            v128_andnot(self, other)
+        }
+
+        #[inline(always)]
+        unsafe fn xor(self, other: Self) -> Self {
+            v128_xor(self, other)
+        }
+
+        #[inline(always)]
+        unsafe fn not(self) -> Self {
+            v128_not(self)
+        }
+
+        #[inline(always)]
+        unsafe fn is_zero(self) -> bool {
+            !v128_any_true(self)
+        }
+
+        #[inline(always)]
+        unsafe fn count_ones(self) -> usize {
+            // `i64.popcnt` on each half is cheaper than summing the bytes of
+            // `i8x16.popcnt`.
+            let [low, high]: [u64; 2] = mem::transmute(self);
+            (low.count_ones() + high.count_ones()) as usize
+        }
+    }
+}
+
+#[cfg(not(any(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ),
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(target_arch = "wasm32", target_feature = "simd128"),
+)))]
+mod scalar {
+    use super::Lane;
+    use crate::Chunk;
+
+    pub type Vector = Chunk;
+
+    impl Lane for Chunk {
+        #[inline(always)]
+        unsafe fn or(self, other: Self) -> Self {
+            self | other
+        }
+
+        #[inline(always)]
+        unsafe fn and(self, other: Self) -> Self {
+            self & other
+        }
+
+        #[inline(always)]
+        unsafe fn and_not(self, other: Self) -> Self {
+            self & !other
+        }
+
+        #[inline(always)]
+        unsafe fn xor(self, other: Self) -> Self {
+            self ^ other
+        }
+
+        #[inline(always)]
+        unsafe fn not(self) -> Self {
+            !self
+        }
+
+        #[inline(always)]
+        unsafe fn is_zero(self) -> bool {
+            self == 0
+        }
+
+        #[inline(always)]
+        unsafe fn count_ones(self) -> usize {
+            self.count_ones() as usize
+        }
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx2",
+))]
+use avx2::Vector;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use neon::Vector;
+#[cfg(not(any(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ),
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(target_arch = "wasm32", target_feature = "simd128"),
+)))]
+use scalar::Vector;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use simd128::Vector;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2",
+    not(target_feature = "avx2"),
+))]
+use sse2::Vector;
+
+/// The number of vectors in a set.
+const NUM_LANES: usize = mem::size_of::<ByteSet>() / mem::size_of::<Vector>();
+
+type Lanes = [Vector; NUM_LANES];
+
+#[inline(always)]
+fn to_lanes(set: &ByteSet) -> Lanes {
+    // SAFETY: Every vector type is plain bits with an alignment of at most 32,
+    // and `NUM_LANES` of them are exactly the size of a set.
+    unsafe { mem::transmute(*set) }
+}
+
+#[inline(always)]
+fn from_lanes(lanes: Lanes) -> ByteSet {
+    // SAFETY: Every bit pattern is a valid set.
+    unsafe { mem::transmute(lanes) }
+}
+
+/// Combines each pair of vectors of `a` and `b` with `f`.
+#[inline(always)]
+fn map(
+    a: &ByteSet,
+    b: &ByteSet,
+    f: impl Fn(Vector, Vector) -> Vector,
+) -> ByteSet {
+    let (a, b) = (to_lanes(a), to_lanes(b));
+
+    let mut lanes = a;
+    for (lane, (&a, &b)) in lanes.iter_mut().zip(a.iter().zip(&b)) {
+        *lane = f(a, b);
+    }
+    from_lanes(lanes)
+}
+
+/// Returns `true` if combining each pair of vectors of `a` and `b` with `f`
+/// leaves no bits set.
+#[inline(always)]
+fn is_zero(
+    a: &ByteSet,
+    b: &ByteSet,
+    f: impl Fn(Vector, Vector) -> Vector,
+) -> bool {
+    let (a, b) = (to_lanes(a), to_lanes(b));
+
+    let mut any = f(a[0], b[0]);
+    for (&a, &b) in a.iter().zip(&b).skip(1) {
+        // SAFETY: See `Lane`.
+        any = unsafe { any.or(f(a, b)) };
+    }
+    // SAFETY: See `Lane`.
+    unsafe { any.is_zero() }
+}
+
+#[inline]
+pub(crate) fn union(a: &ByteSet, b: &ByteSet) -> ByteSet {
+    // SAFETY: See `Lane`.
+    map(a, b, |a, b| unsafe { a.or(b) })
+}
+
+#[inline]
+pub(crate) fn intersection(a: &ByteSet, b: &ByteSet) -> ByteSet {
+    // SAFETY: See `Lane`.
+    map(a, b, |a, b| unsafe { a.and(b) })
+}
+
+#[inline]
+pub(crate) fn difference(a: &ByteSet, b: &ByteSet) -> ByteSet {
+    // SAFETY: See `Lane`.
+    map(a, b, |a, b| unsafe { a.and_not(b) })
+}
+
+#[inline]
+pub(crate) fn symmetric_difference(a: &ByteSet, b: &ByteSet) -> ByteSet {
+    // SAFETY: See `Lane`.
+    map(a, b, |a, b| unsafe { a.xor(b) })
+}
+
+#[inline]
+pub(crate) fn not(a: &ByteSet) -> ByteSet {
+    // SAFETY: See `Lane`.
+    map(a, a, |a, _| unsafe { a.not() })
+}
+
+#[inline]
+pub(crate) fn len(a: &ByteSet) -> usize {
+    // SAFETY: See `Lane`.
+    to_lanes(a)
+        .iter()
+        .map(|&lane| unsafe { Lane::count_ones(lane) })
+        .sum()
+}
+
+#[inline]
+pub(crate) fn eq(a: &ByteSet, b: &ByteSet) -> bool {
+    // SAFETY: See `Lane`.
+    is_zero(a, b, |a, b| unsafe { a.xor(b) })
+}
+
+#[inline]
+pub(crate) fn is_subset(a: &ByteSet, b: &ByteSet) -> bool {
+    // SAFETY: See `Lane`.
+    is_zero(a, b, |a, b| unsafe { a.and_not(b) })
+}
+
+#[inline]
+pub(crate) fn is_disjoint(a: &ByteSet, b: &ByteSet) -> bool {
+    // SAFETY: See `Lane`.
+    is_zero(a, b, |a, b| unsafe { a.and(b) })
+}
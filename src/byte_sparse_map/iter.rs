@@ -0,0 +1,169 @@
+extern crate alloc;
+
+use super::ByteSparseMap;
+use alloc::vec;
+use core::{fmt, iter, slice};
+
+/// An iterator over the entries of a [`ByteSparseMap`], ordered by key.
+///
+/// This is created by [`ByteSparseMap::iter`].
+///
+/// [`ByteSparseMap`]: struct.ByteSparseMap.html
+/// [`ByteSparseMap::iter`]: struct.ByteSparseMap.html#method.iter
+pub struct Iter<'a, V> {
+    keys: crate::Iter,
+    values: slice::Iter<'a, V>,
+}
+
+/// An iterator over the entries of a [`ByteSparseMap`] with mutable references
+/// to values, ordered by key.
+///
+/// This is created by [`ByteSparseMap::iter_mut`].
+///
+/// [`ByteSparseMap`]: struct.ByteSparseMap.html
+/// [`ByteSparseMap::iter_mut`]: struct.ByteSparseMap.html#method.iter_mut
+pub struct IterMut<'a, V> {
+    keys: crate::Iter,
+    values: slice::IterMut<'a, V>,
+}
+
+/// An owning iterator over the entries of a [`ByteSparseMap`], ordered by key.
+///
+/// This is created by calling `into_iter` on a [`ByteSparseMap`].
+///
+/// [`ByteSparseMap`]: struct.ByteSparseMap.html
+pub struct IntoIter<V> {
+    keys: crate::Iter,
+    values: vec::IntoIter<V>,
+}
+
+impl<'a, V> Iter<'a, V> {
+    #[inline]
+    pub(super) fn new(
+        keys: crate::ByteSet,
+        values: slice::Iter<'a, V>,
+    ) -> Self {
+        Self {
+            keys: keys.into_iter(),
+            values,
+        }
+    }
+}
+
+impl<'a, V> IterMut<'a, V> {
+    #[inline]
+    pub(super) fn new(
+        keys: crate::ByteSet,
+        values: slice::IterMut<'a, V>,
+    ) -> Self {
+        Self {
+            keys: keys.into_iter(),
+            values,
+        }
+    }
+}
+
+// Keys and values are stored in the same order and have the same length, so
+// each iterator pairs them in lockstep from either end.
+macro_rules! impl_iter {
+    ($($iter:ty => $value:ty,)+) => {
+        $(
+            impl<'a, V> Iterator for $iter {
+                type Item = (u8, $value);
+
+                #[inline]
+                fn next(&mut self) -> Option<Self::Item> {
+                    let key = self.keys.next()?;
+                    let value = self.values.next()?;
+                    Some((key, value))
+                }
+
+                #[inline]
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    self.values.size_hint()
+                }
+            }
+
+            impl<'a, V> DoubleEndedIterator for $iter {
+                #[inline]
+                fn next_back(&mut self) -> Option<Self::Item> {
+                    let key = self.keys.next_back()?;
+                    let value = self.values.next_back()?;
+                    Some((key, value))
+                }
+            }
+
+            impl<'a, V> ExactSizeIterator for $iter {}
+            impl<'a, V> iter::FusedIterator for $iter {}
+        )+
+    };
+}
+
+impl_iter! {
+    Iter<'a, V> => &'a V,
+    IterMut<'a, V> => &'a mut V,
+    IntoIter<V> => V,
+}
+
+impl<V> IntoIterator for ByteSparseMap<V> {
+    type Item = (u8, V);
+    type IntoIter = IntoIter<V>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<V> {
+        IntoIter {
+            keys: self.keys.into_iter(),
+            values: self.values.into_iter(),
+        }
+    }
+}
+
+impl<'a, V> IntoIterator for &'a ByteSparseMap<V> {
+    type Item = (u8, &'a V);
+    type IntoIter = Iter<'a, V>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, V> {
+        self.iter()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a mut ByteSparseMap<V> {
+    type Item = (u8, &'a mut V);
+    type IntoIter = IterMut<'a, V>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, V> {
+        self.iter_mut()
+    }
+}
+
+impl<V> Clone for Iter<'_, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            keys: self.keys,
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for Iter<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for IterMut<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let values = self.values.as_slice();
+        f.debug_list().entries(self.keys.zip(values)).finish()
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for IntoIter<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let values = self.values.as_slice();
+        f.debug_list().entries(self.keys.zip(values)).finish()
+    }
+}
@@ -0,0 +1,227 @@
+//! A compact map keyed by [`u8`] that only stores present values.
+//!
+//! See [`ByteSparseMap`] for more info.
+//!
+//! [`u8`]: https://doc.rust-lang.org/std/primitive.u8.html
+//! [`ByteSparseMap`]: struct.ByteSparseMap.html
+
+extern crate alloc;
+
+use crate::ByteSet;
+use alloc::vec::Vec;
+use core::{mem, slice};
+
+// These modules must appear in this order to make documentation easier to read.
+// The space between ensures rustfmt does not reorder them.
+mod iter;
+pub use iter::{IntoIter, Iter, IterMut};
+
+mod traits;
+
+/// A compact map keyed by [`u8`] that only stores present values.
+///
+/// # Implementation
+///
+/// Values are stored contiguously in a [`Vec`], ordered by key. A [`ByteSet`]
+/// bitmap tracks which keys are present, and the index of a key's value is the
+/// number of present keys less than it (its _rank_), which is computed with a
+/// few popcounts.
+///
+/// This is the node layout used by hash array mapped tries (HAMTs) and by the
+/// 256-way nodes of adaptive radix trees. Compared to [`ByteMap`], lookup is
+/// slightly slower and insertion or removal shifts later values, but memory use
+/// is proportional to the number of entries rather than always reserving space
+/// for 256 values.
+///
+/// Entries are iterated in order of their keys, from least to greatest.
+///
+/// # Examples
+///
+/// ```
+/// use byte_set::ByteSparseMap;
+///
+/// let mut map = ByteSparseMap::new();
+/// map.insert(b'z', "zee");
+/// map.insert(b'a', "ay");
+///
+/// assert_eq!(map.get(b'z'), Some(&"zee"));
+/// assert_eq!(map.values().as_slice(), ["ay", "zee"]);
+/// ```
+///
+/// [`u8`]: https://doc.rust-lang.org/std/primitive.u8.html
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+/// [`ByteSet`]: ../struct.ByteSet.html
+/// [`ByteMap`]: ../byte_map/struct.ByteMap.html
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ByteSparseMap<V> {
+    /// The keys of `values`, where the value for a key is at its rank.
+    keys: ByteSet,
+    values: Vec<V>,
+}
+
+impl<V> ByteSparseMap<V> {
+    /// Returns a map containing no entries.
+    ///
+    /// This does not allocate.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            keys: ByteSet::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Returns a map containing no entries, with space for at least `capacity`
+    /// values.
+    #[inline]
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            keys: ByteSet::new(),
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of values `self` can hold without reallocating.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.values.capacity()
+    }
+
+    /// Shrinks the capacity of `self` as much as possible.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.values.shrink_to_fit();
+    }
+
+    /// Returns the number of entries in `self`.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if `self` contains no entries.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the set of keys in `self`.
+    ///
+    /// [`ByteSet`]: ../struct.ByteSet.html
+    #[inline]
+    #[must_use]
+    pub const fn keys(&self) -> ByteSet {
+        self.keys
+    }
+
+    /// Returns `true` if `self` contains a value for `key`.
+    #[inline]
+    #[must_use]
+    pub const fn contains_key(&self, key: u8) -> bool {
+        self.keys.contains(key)
+    }
+
+    /// Returns the index of the value for `key` within
+    /// [`values`](#method.values), or `None` if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_set::ByteSparseMap;
+    ///
+    /// let map: ByteSparseMap<_> = b"hello".iter().map(|&b| (b, ())).collect();
+    ///
+    /// assert_eq!(map.index_of(b'e'), Some(0));
+    /// assert_eq!(map.index_of(b'o'), Some(3));
+    /// assert_eq!(map.index_of(b'z'), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn index_of(&self, key: u8) -> Option<usize> {
+        self.keys.index_of(key)
+    }
+
+    /// Returns a reference to the value for `key`, or `None` if there is none.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, key: u8) -> Option<&V> {
+        let index = self.index_of(key)?;
+        // SAFETY: There is a value for each key in `keys`.
+        Some(unsafe { self.values.get_unchecked(index) })
+    }
+
+    /// Returns a mutable reference to the value for `key`, or `None` if there
+    /// is none.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, key: u8) -> Option<&mut V> {
+        let index = self.index_of(key)?;
+        // SAFETY: There is a value for each key in `keys`.
+        Some(unsafe { self.values.get_unchecked_mut(index) })
+    }
+
+    /// Inserts `value` for `key`, returning the previous value for `key` if
+    /// there was one.
+    ///
+    /// Inserting a new key shifts the values of all greater keys.
+    pub fn insert(&mut self, key: u8, value: V) -> Option<V> {
+        let index = self.keys.rank(key);
+
+        if self.contains_key(key) {
+            Some(mem::replace(&mut self.values[index], value))
+        } else {
+            self.values.insert(index, value);
+            self.keys.insert(key);
+            None
+        }
+    }
+
+    /// Removes the value for `key` and returns it, or `None` if there is none.
+    ///
+    /// Removing a key shifts the values of all greater keys.
+    pub fn remove(&mut self, key: u8) -> Option<V> {
+        let index = self.index_of(key)?;
+        self.keys.remove(key);
+        Some(self.values.remove(index))
+    }
+
+    /// Removes all entries from `self`.
+    ///
+    /// This does not change the capacity of `self`.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.keys.clear();
+        self.values.clear();
+    }
+
+    /// Returns an iterator over the entries of `self`, ordered by key.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, V> {
+        Iter::new(self.keys, self.values.iter())
+    }
+
+    /// Returns an iterator over the entries of `self` with mutable references
+    /// to values, ordered by key.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, V> {
+        IterMut::new(self.keys, self.values.iter_mut())
+    }
+
+    /// Returns an iterator over the values of `self`, ordered by key.
+    #[inline]
+    pub fn values(&self) -> slice::Iter<'_, V> {
+        self.values.iter()
+    }
+
+    /// Returns an iterator over mutable references to the values of `self`,
+    /// ordered by key.
+    #[inline]
+    pub fn values_mut(&mut self) -> slice::IterMut<'_, V> {
+        self.values.iter_mut()
+    }
+}
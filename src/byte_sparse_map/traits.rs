@@ -0,0 +1,124 @@
+use super::ByteSparseMap;
+use crate::ByteMap;
+use core::{fmt, iter::FromIterator, ops};
+
+impl<V> Default for ByteSparseMap<V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for ByteSparseMap<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self).finish()
+    }
+}
+
+impl<V> ops::Index<u8> for ByteSparseMap<V> {
+    type Output = V;
+
+    /// Returns a reference to the value for `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is not in the map.
+    #[inline]
+    fn index(&self, key: u8) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+impl<V> From<ByteMap<V>> for ByteSparseMap<V> {
+    #[inline]
+    fn from(map: ByteMap<V>) -> Self {
+        let mut sparse = Self::with_capacity(map.len());
+        sparse.keys = map.keys();
+        sparse
+            .values
+            .extend(map.into_iter().map(|(_, value)| value));
+        sparse
+    }
+}
+
+impl<V> From<ByteSparseMap<V>> for ByteMap<V> {
+    #[inline]
+    fn from(map: ByteSparseMap<V>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl<V> Extend<(u8, V)> for ByteSparseMap<V> {
+    fn extend<T: IntoIterator<Item = (u8, V)>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|(key, value)| {
+            self.insert(key, value);
+        });
+    }
+}
+
+impl<'a, V: Copy> Extend<(u8, &'a V)> for ByteSparseMap<V> {
+    fn extend<T: IntoIterator<Item = (u8, &'a V)>>(&mut self, iter: T) {
+        self.extend(iter.into_iter().map(|(key, &value)| (key, value)));
+    }
+}
+
+impl<V> FromIterator<(u8, V)> for ByteSparseMap<V> {
+    fn from_iter<T: IntoIterator<Item = (u8, V)>>(iter: T) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<V: serde::Serialize> serde::Serialize for ByteSparseMap<V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, V: serde::Deserialize<'de>> serde::Deserialize<'de>
+    for ByteSparseMap<V>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use core::marker::PhantomData;
+
+        struct ByteSparseMapVisitor<V>(PhantomData<V>);
+
+        impl<'de, V: serde::Deserialize<'de>> serde::de::Visitor<'de>
+            for ByteSparseMapVisitor<V>
+        {
+            type Value = ByteSparseMap<V>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map keyed by bytes")
+            }
+
+            fn visit_map<A>(
+                self,
+                mut access: A,
+            ) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let capacity = access.size_hint().unwrap_or(0).min(256);
+                let mut map = ByteSparseMap::with_capacity(capacity);
+                while let Some((key, value)) = access.next_entry::<u8, V>()? {
+                    map.insert(key, value);
+                }
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_map(ByteSparseMapVisitor(PhantomData))
+    }
+}
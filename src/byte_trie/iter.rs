@@ -0,0 +1,94 @@
+extern crate alloc;
+
+use super::Node;
+use crate::byte_sparse_map;
+use alloc::vec::Vec;
+use core::{fmt, iter};
+
+/// An iterator over the entries of a [`ByteTrie`], in lexicographic order of
+/// their keys.
+///
+/// Keys are not stored contiguously in a trie, so each one is yielded as a new
+/// [`Vec<u8>`].
+///
+/// This is created by [`ByteTrie::iter`] and [`ByteTrie::iter_prefix`].
+///
+/// [`ByteTrie`]: struct.ByteTrie.html
+/// [`ByteTrie::iter`]: struct.ByteTrie.html#method.iter
+/// [`ByteTrie::iter_prefix`]: struct.ByteTrie.html#method.iter_prefix
+/// [`Vec<u8>`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+pub struct Iter<'a, V> {
+    /// The key of the node whose children are at the top of `stack`.
+    key: Vec<u8>,
+
+    /// The value of the starting node, which comes before all of its children.
+    first: Option<&'a V>,
+
+    /// The remaining children of each node along the path to `key`.
+    stack: Vec<byte_sparse_map::Iter<'a, Node<V>>>,
+}
+
+impl<'a, V> Iter<'a, V> {
+    #[inline]
+    pub(super) fn new(key: Vec<u8>, node: Option<&'a Node<V>>) -> Self {
+        let mut stack = Vec::new();
+        stack.extend(node.map(|node| node.children.iter()));
+
+        Self {
+            key,
+            first: node.and_then(|node| node.value.as_ref()),
+            stack,
+        }
+    }
+}
+
+impl<'a, V> Iterator for Iter<'a, V> {
+    type Item = (Vec<u8>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(value) = self.first.take() {
+            return Some((self.key.clone(), value));
+        }
+
+        loop {
+            match self.stack.last_mut()?.next() {
+                Some((byte, node)) => {
+                    self.key.push(byte);
+                    self.stack.push(node.children.iter());
+
+                    if let Some(value) = &node.value {
+                        return Some((self.key.clone(), value));
+                    }
+                }
+                None => {
+                    self.stack.pop();
+
+                    // The starting node's key is not part of the traversal.
+                    if !self.stack.is_empty() {
+                        self.key.pop();
+                    }
+                }
+            }
+        }
+    }
+}
+
+// `Iter` does not produce more values after `None` is reached.
+impl<V> iter::FusedIterator for Iter<'_, V> {}
+
+impl<V> Clone for Iter<'_, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            first: self.first,
+            stack: self.stack.clone(),
+        }
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for Iter<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
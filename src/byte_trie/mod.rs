@@ -0,0 +1,297 @@
+//! A prefix tree over byte strings whose nodes track their children with a
+//! [`ByteSet`].
+//!
+//! See [`ByteTrie`] for more info.
+//!
+//! [`ByteSet`]: ../struct.ByteSet.html
+//! [`ByteTrie`]: struct.ByteTrie.html
+
+extern crate alloc;
+
+use crate::{ByteSet, ByteSparseMap};
+use alloc::vec::Vec;
+
+// These modules must appear in this order to make documentation easier to read.
+// The space between ensures rustfmt does not reorder them.
+mod iter;
+pub use iter::Iter;
+
+mod traits;
+
+/// A prefix tree mapping byte strings to values.
+///
+/// # Implementation
+///
+/// Each node stores its children in a [`ByteSparseMap`], so a [`ByteSet`]
+/// records which bytes have a child and the children themselves are stored
+/// densely, ordered by byte. Finding a child takes a few popcounts to compute
+/// its rank, and a node only uses memory for the children it has.
+///
+/// Because children are ordered by byte, entries are iterated in
+/// lexicographic order of their keys.
+///
+/// # Examples
+///
+/// ```
+/// use byte_set::{ByteSet, ByteTrie};
+///
+/// let mut trie = ByteTrie::new();
+/// trie.insert(b"/api", 1);
+/// trie.insert(b"/api/users", 2);
+/// trie.insert(b"/about", 3);
+///
+/// assert_eq!(trie.get(b"/api"), Some(&1));
+/// assert_eq!(
+///     trie.longest_prefix_match(b"/api/users/42"),
+///     Some((&b"/api/users"[..], &2)),
+/// );
+/// assert_eq!(trie.next_bytes(b"/a"), ByteSet::from(&b"bp"[..]));
+/// ```
+///
+/// [`ByteSet`]: ../struct.ByteSet.html
+/// [`ByteSparseMap`]: ../byte_sparse_map/struct.ByteSparseMap.html
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ByteTrie<V> {
+    root: Node<V>,
+    len: usize,
+}
+
+/// A node of a [`ByteTrie`].
+///
+/// Each byte of a key adds a level of nesting, so `Clone`, `PartialEq`,
+/// `Hash`, and `Drop` walk nodes with an explicit stack instead of recursing.
+/// Otherwise, a long enough key would overflow the call stack.
+///
+/// [`ByteTrie`]: struct.ByteTrie.html
+struct Node<V> {
+    /// The value for the key ending at this node.
+    value: Option<V>,
+    children: ByteSparseMap<Node<V>>,
+}
+
+impl<V> Node<V> {
+    #[inline]
+    const fn new() -> Self {
+        Self {
+            value: None,
+            children: ByteSparseMap::new(),
+        }
+    }
+
+    /// Removes the value for `key` relative to this node, along with any nodes
+    /// that become empty.
+    fn remove(&mut self, key: &[u8]) -> Option<V> {
+        if key.is_empty() {
+            return self.value.take();
+        }
+
+        // Find the deepest node along `key` that still leads to another key
+        // once `key` is removed. Every node below it on the path has one child
+        // and no value, so the whole branch below it can be cut off at once.
+        let mut keep = 0;
+        let mut node = &*self;
+        for (depth, &byte) in key.iter().enumerate() {
+            if node.value.is_some() || node.children.len() > 1 {
+                keep = depth;
+            }
+            node = node.children.get(byte)?;
+        }
+        node.value.as_ref()?;
+
+        let is_leaf = node.children.is_empty();
+        let mut node = self;
+        if !is_leaf {
+            for &byte in key {
+                node = node.children.get_mut(byte)?;
+            }
+            return node.value.take();
+        }
+
+        for &byte in &key[..keep] {
+            node = node.children.get_mut(byte)?;
+        }
+        let mut branch = node.children.remove(key[keep])?;
+
+        let mut node = &mut branch;
+        for &byte in &key[keep + 1..] {
+            node = node.children.get_mut(byte)?;
+        }
+        node.value.take()
+    }
+}
+
+impl<V> ByteTrie<V> {
+    /// Returns a trie containing no entries.
+    ///
+    /// This does not allocate.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            root: Node::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of entries in `self`.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if `self` contains no entries.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all entries from `self`.
+    #[inline]
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns the node reached by following `prefix` from the root, or `None`
+    /// if no key starts with `prefix`.
+    fn node(&self, prefix: &[u8]) -> Option<&Node<V>> {
+        prefix
+            .iter()
+            .try_fold(&self.root, |node, &byte| node.children.get(byte))
+    }
+
+    /// Returns `true` if `self` contains a value for `key`.
+    #[inline]
+    #[must_use]
+    pub fn contains_key(&self, key: &[u8]) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns a reference to the value for `key`, or `None` if there is none.
+    #[must_use]
+    pub fn get(&self, key: &[u8]) -> Option<&V> {
+        self.node(key)?.value.as_ref()
+    }
+
+    /// Returns a mutable reference to the value for `key`, or `None` if there
+    /// is none.
+    #[must_use]
+    pub fn get_mut(&mut self, key: &[u8]) -> Option<&mut V> {
+        let mut node = &mut self.root;
+        for &byte in key {
+            node = node.children.get_mut(byte)?;
+        }
+        node.value.as_mut()
+    }
+
+    /// Inserts `value` for `key`, returning the previous value for `key` if
+    /// there was one.
+    pub fn insert(&mut self, key: &[u8], value: V) -> Option<V> {
+        let mut node = &mut self.root;
+        for &byte in key {
+            if !node.children.contains_key(byte) {
+                node.children.insert(byte, Node::new());
+            }
+            // A child for `byte` was just inserted if there was none.
+            node = node.children.get_mut(byte).unwrap();
+        }
+
+        let previous = node.value.replace(value);
+        if previous.is_none() {
+            self.len += 1;
+        }
+        previous
+    }
+
+    /// Removes the value for `key` and returns it, or `None` if there is none.
+    ///
+    /// Nodes that no longer lead to any key are removed as well.
+    pub fn remove(&mut self, key: &[u8]) -> Option<V> {
+        let value = self.root.remove(key)?;
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Returns the longest prefix of `key` that has a value in `self`, along
+    /// with that value, or `None` if no prefix of `key` has a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteTrie;
+    /// let trie: ByteTrie<_> = vec![("a", 1), ("abc", 2)].into_iter().collect();
+    ///
+    /// assert_eq!(trie.longest_prefix_match(b"ab"), Some((&b"a"[..], &1)));
+    /// assert_eq!(trie.longest_prefix_match(b"abcd"), Some((&b"abc"[..], &2)));
+    /// assert_eq!(trie.longest_prefix_match(b"b"), None);
+    /// ```
+    #[must_use]
+    pub fn longest_prefix_match<'k>(
+        &self,
+        key: &'k [u8],
+    ) -> Option<(&'k [u8], &V)> {
+        let mut node = &self.root;
+        let mut longest = node.value.as_ref().map(|value| (0, value));
+
+        for (i, &byte) in key.iter().enumerate() {
+            node = match node.children.get(byte) {
+                Some(child) => child,
+                None => break,
+            };
+            if let Some(value) = &node.value {
+                longest = Some((i + 1, value));
+            }
+        }
+
+        longest.map(|(len, value)| (&key[..len], value))
+    }
+
+    /// Returns the set of bytes that follow `prefix` in at least one key, or
+    /// an empty set if no key starts with `prefix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::{ByteSet, ByteTrie};
+    /// let trie: ByteTrie<_> =
+    ///     vec![("car", ()), ("cat", ()), ("cow", ())].into_iter().collect();
+    ///
+    /// assert_eq!(trie.next_bytes(b"c"), ByteSet::from(&b"ao"[..]));
+    /// assert_eq!(trie.next_bytes(b"ca"), ByteSet::from(&b"rt"[..]));
+    /// assert_eq!(trie.next_bytes(b"cat"), ByteSet::new());
+    /// assert_eq!(trie.next_bytes(b"dog"), ByteSet::new());
+    /// ```
+    #[must_use]
+    pub fn next_bytes(&self, prefix: &[u8]) -> ByteSet {
+        match self.node(prefix) {
+            Some(node) => node.children.keys(),
+            None => ByteSet::new(),
+        }
+    }
+
+    /// Returns an iterator over the entries of `self`, in lexicographic order
+    /// of their keys.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, V> {
+        Iter::new(Vec::new(), Some(&self.root))
+    }
+
+    /// Returns an iterator over the entries of `self` whose keys start with
+    /// `prefix`, in lexicographic order of their keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::ByteTrie;
+    /// let trie: ByteTrie<_> =
+    ///     vec![("tea", 1), ("ten", 2), ("to", 3)].into_iter().collect();
+    ///
+    /// let entries: Vec<_> = trie.iter_prefix(b"te").collect();
+    /// assert_eq!(entries, [(b"tea".to_vec(), &1), (b"ten".to_vec(), &2)]);
+    /// ```
+    #[inline]
+    pub fn iter_prefix(&self, prefix: &[u8]) -> Iter<'_, V> {
+        Iter::new(prefix.to_vec(), self.node(prefix))
+    }
+}
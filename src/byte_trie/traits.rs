@@ -0,0 +1,150 @@
+extern crate alloc;
+
+use super::{ByteTrie, Iter, Node};
+use crate::{byte_sparse_map, ByteSparseMap};
+use alloc::{vec, vec::Vec};
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FromIterator,
+    mem, ops,
+};
+
+impl<V: Clone> Clone for Node<V> {
+    fn clone(&self) -> Self {
+        // A node being cloned, the byte it is reached by, its remaining
+        // children, and the clones of its earlier children.
+        type Frame<'a, V> = (
+            u8,
+            &'a Node<V>,
+            byte_sparse_map::Iter<'a, Node<V>>,
+            ByteSparseMap<Node<V>>,
+        );
+
+        fn frame<V>(byte: u8, node: &Node<V>) -> Frame<'_, V> {
+            let clones = ByteSparseMap::with_capacity(node.children.len());
+            (byte, node, node.children.iter(), clones)
+        }
+
+        // The ancestors of `current`, each waiting for its next child.
+        let mut stack = Vec::new();
+        let mut current = frame(0, self);
+
+        loop {
+            if let Some((byte, child)) = current.2.next() {
+                stack.push(mem::replace(&mut current, frame(byte, child)));
+                continue;
+            }
+
+            let (byte, node, _, children) = current;
+            let clone = Node {
+                value: node.value.clone(),
+                children,
+            };
+
+            current = match stack.pop() {
+                Some(parent) => parent,
+                None => return clone,
+            };
+            current.3.insert(byte, clone);
+        }
+    }
+}
+
+impl<V: PartialEq> PartialEq for Node<V> {
+    fn eq(&self, other: &Self) -> bool {
+        let mut stack = vec![(self, other)];
+
+        while let Some((a, b)) = stack.pop() {
+            if a.value != b.value || a.children.keys() != b.children.keys() {
+                return false;
+            }
+            stack.extend(a.children.values().zip(b.children.values()));
+        }
+        true
+    }
+}
+
+impl<V: Eq> Eq for Node<V> {}
+
+impl<V: Hash> Hash for Node<V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut stack = vec![self];
+
+        while let Some(node) = stack.pop() {
+            node.value.hash(state);
+            node.children.keys().hash(state);
+            stack.extend(node.children.values());
+        }
+    }
+}
+
+impl<V> Drop for Node<V> {
+    fn drop(&mut self) {
+        // Move descendants onto the heap so that each one is dropped after
+        // its children have been taken, which leaves nothing to recurse into.
+        let mut nodes: Vec<Node<V>> = Vec::new();
+        let mut children = mem::take(&mut self.children);
+
+        loop {
+            nodes.extend(children.into_iter().map(|(_, node)| node));
+            children = match nodes.pop() {
+                Some(mut node) => mem::take(&mut node.children),
+                None => break,
+            };
+        }
+    }
+}
+
+impl<V> Default for ByteTrie<V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for ByteTrie<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self).finish()
+    }
+}
+
+impl<K: AsRef<[u8]>, V> ops::Index<K> for ByteTrie<V> {
+    type Output = V;
+
+    /// Returns a reference to the value for `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is not in the trie.
+    #[inline]
+    fn index(&self, key: K) -> &V {
+        self.get(key.as_ref()).expect("no entry found for key")
+    }
+}
+
+impl<K: AsRef<[u8]>, V> Extend<(K, V)> for ByteTrie<V> {
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|(key, value)| {
+            self.insert(key.as_ref(), value);
+        });
+    }
+}
+
+impl<K: AsRef<[u8]>, V> FromIterator<(K, V)> for ByteTrie<V> {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut trie = Self::new();
+        trie.extend(iter);
+        trie
+    }
+}
+
+impl<'a, V> IntoIterator for &'a ByteTrie<V> {
+    type Item = (Vec<u8>, &'a V);
+    type IntoIter = Iter<'a, V>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, V> {
+        self.iter()
+    }
+}
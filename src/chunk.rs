@@ -1,13 +1,30 @@
-//! Operations over chunks (`usize`).
+//! Operations over chunks (`u64` or `u32`).
 
 use core::mem;
 
-// TODO(#3): Use 64-bit chunk on 32-bit targets with 64-bit instructions.
-
+// This is the only place that depends on the chunk width. Everything else
+// iterates over `ByteSet::NUM_SLOTS` chunks with constant bounds, which the
+// compiler unrolls and, since `ByteSet` is 32-byte aligned, turns into vector
+// instructions when they are enabled: one AVX2 operation or two SSE2/NEON
+// operations per set.
+//
+// 64-bit chunks are also used on 32-bit ABIs of 64-bit architectures (x32 and
+// ILP32) and on WebAssembly, which all have native 64-bit instructions.
+//
 // Not using `usize` in order to work on platforms with other pointer sizes.
-#[cfg(target_pointer_width = "64")]
+#[cfg(any(
+    target_pointer_width = "64",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+))]
 pub type Chunk = u64;
-#[cfg(not(target_pointer_width = "64"))]
+#[cfg(not(any(
+    target_pointer_width = "64",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+)))]
 pub type Chunk = u32;
 
 const SLOT_NUM_BITS: usize = mem::size_of::<Chunk>() * 8;
@@ -0,0 +1,214 @@
+use crate::ByteSet;
+use core::{fmt, iter};
+
+/// A fixed-length sequence of [`ByteSet`]s compiled for searching byte slices.
+///
+/// Each position of the pattern matches any byte in its set, so
+/// `[0-9][0-9][0-9]-[0-9][0-9]` is a pattern of 6 sets. Patterns have 1 to
+/// [`MAX_LEN`] positions.
+///
+/// # Implementation
+///
+/// Searching uses the [shift-or] algorithm. Creating a pattern precomputes a
+/// 64-bit mask for each byte, whose bit `i` is clear if position `i` accepts
+/// that byte. The state of every partial match is then kept in a single
+/// integer, which is updated with one shift, one lookup, and one OR per byte
+/// of the haystack.
+///
+/// # Examples
+///
+/// ```
+/// use byte_set::{ByteSet, ClassPattern};
+///
+/// let digit = ByteSet::ASCII_DIGIT;
+/// let dash = ByteSet::from_byte(b'-');
+/// let pattern = ClassPattern::new(&[digit, digit, digit, dash, digit, digit])
+///     .unwrap();
+///
+/// assert_eq!(pattern.find(b"call 555-01 or 555-02"), Some(5));
+/// assert!(!pattern.is_match(b"55-501"));
+/// ```
+///
+/// [`ByteSet`]: struct.ByteSet.html
+/// [`MAX_LEN`]: #associatedconstant.MAX_LEN
+/// [shift-or]: https://en.wikipedia.org/wiki/Bitap_algorithm
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ClassPattern {
+    /// For each byte, the positions that do not accept it.
+    masks: [u64; 256],
+    len: usize,
+}
+
+impl ClassPattern {
+    /// The maximum number of positions in a pattern.
+    pub const MAX_LEN: usize = 64;
+
+    /// Compiles `sets` into a pattern whose position `i` matches any byte in
+    /// `sets[i]`.
+    ///
+    /// Returns `None` if `sets` is empty or has more than
+    /// [`MAX_LEN`](#associatedconstant.MAX_LEN) sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_set::{ByteSet, ClassPattern};
+    /// assert!(ClassPattern::new(&[ByteSet::ASCII_DIGIT; 64]).is_some());
+    /// assert!(ClassPattern::new(&[ByteSet::ASCII_DIGIT; 65]).is_none());
+    /// assert!(ClassPattern::new(&[]).is_none());
+    /// ```
+    #[must_use]
+    pub const fn new(sets: &[ByteSet]) -> Option<Self> {
+        let len = sets.len();
+        if len == 0 || len > Self::MAX_LEN {
+            return None;
+        }
+
+        let mut masks = [!0u64; 256];
+        let mut i = 0;
+        while i < len {
+            let mut byte = 0;
+            while byte < 256 {
+                if sets[i].contains(byte as u8) {
+                    masks[byte] &= !(1 << i);
+                }
+                byte += 1;
+            }
+            i += 1;
+        }
+
+        Some(Self { masks, len })
+    }
+
+    /// Returns the number of positions in the pattern, which is also the
+    /// length of every match.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::len_without_is_empty)] // Patterns are never empty.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the set of bytes accepted at `index`, or `None` if `index` is
+    /// out of bounds.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<ByteSet> {
+        if index >= self.len {
+            return None;
+        }
+
+        let mut set = ByteSet::new();
+        for (byte, &mask) in self.masks.iter().enumerate() {
+            set.set(byte as u8, mask & (1 << index) == 0);
+        }
+        Some(set)
+    }
+
+    /// Returns the index of the first match in `haystack`, or `None` if there
+    /// is none.
+    #[inline]
+    #[must_use]
+    pub fn find(&self, haystack: &[u8]) -> Option<usize> {
+        self.find_from(haystack, 0)
+    }
+
+    /// Returns `true` if the pattern matches anywhere in `haystack`.
+    #[inline]
+    #[must_use]
+    pub fn is_match(&self, haystack: &[u8]) -> bool {
+        self.find(haystack).is_some()
+    }
+
+    /// Returns an iterator over the indices of non-overlapping matches in
+    /// `haystack`, from first to last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_set::{ByteSet, ClassPattern};
+    ///
+    /// let pattern = ClassPattern::new(&[ByteSet::ASCII_DIGIT; 2]).unwrap();
+    /// let indices: Vec<usize> = pattern.find_iter(b"1234 5 67").collect();
+    ///
+    /// assert_eq!(indices, [0, 2, 7]);
+    /// ```
+    #[inline]
+    pub fn find_iter<'p, 'h>(
+        &'p self,
+        haystack: &'h [u8],
+    ) -> ClassMatches<'p, 'h> {
+        ClassMatches {
+            pattern: self,
+            haystack,
+            start: 0,
+        }
+    }
+
+    /// Returns the index of the first match in `haystack` that starts at or
+    /// after `start`.
+    fn find_from(&self, haystack: &[u8], start: usize) -> Option<usize> {
+        let accept = 1 << (self.len - 1);
+
+        // Bit `i` is clear if the last `i + 1` bytes match the first `i + 1`
+        // positions of the pattern.
+        let mut state = !0u64;
+
+        for (i, &byte) in haystack.get(start..)?.iter().enumerate() {
+            state = (state << 1) | self.masks[byte as usize];
+
+            if state & accept == 0 {
+                return Some(start + i + 1 - self.len);
+            }
+        }
+        None
+    }
+}
+
+impl fmt::Debug for ClassPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sets = (0..self.len).filter_map(|index| self.get(index));
+        f.debug_list().entries(sets).finish()
+    }
+}
+
+/// An iterator over the indices of non-overlapping matches of a
+/// [`ClassPattern`].
+///
+/// This is created by [`ClassPattern::find_iter`].
+///
+/// [`ClassPattern`]: struct.ClassPattern.html
+/// [`ClassPattern::find_iter`]: struct.ClassPattern.html#method.find_iter
+#[derive(Clone, Debug)]
+pub struct ClassMatches<'p, 'h> {
+    pattern: &'p ClassPattern,
+    haystack: &'h [u8],
+
+    /// The start of the unsearched part of `haystack`.
+    start: usize,
+}
+
+impl Iterator for ClassMatches<'_, '_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let index = match self.pattern.find_from(self.haystack, self.start) {
+            Some(index) => index,
+            None => {
+                self.start = self.haystack.len();
+                return None;
+            }
+        };
+
+        self.start = index + self.pattern.len;
+        Some(index)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let rest = self.haystack.len() - self.start;
+        (0, Some(rest / self.pattern.len))
+    }
+}
+
+// `ClassMatches` does not produce more values after `None` is reached.
+impl iter::FusedIterator for ClassMatches<'_, '_> {}
@@ -1,4 +1,4 @@
-use crate::{chunk, ByteSet};
+use crate::{byte_set::vector, chunk, ByteSet};
 use core::iter;
 
 /// An iterator over a [`ByteSet`].
@@ -125,7 +125,7 @@ impl DoubleEndedIterator for Iter {
 impl ExactSizeIterator for Iter {
     #[inline]
     fn len(&self) -> usize {
-        self.byte_set.len()
+        vector::len(&self.byte_set)
     }
 }
 
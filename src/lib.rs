@@ -334,8 +334,9 @@
 //! ```
 //!
 //! This bit mask is composed of either `[u64; 4]` or `[u32; 8]` depending on
-//! the target CPU (see [#3]). Because this comes out to only 32 bytes,
-//! [`ByteSet`] implements [`Copy`].
+//! the target CPU (see [#3]), and is aligned to 32 bytes so that it fits in
+//! one AVX2 register or two SSE2 or NEON registers. Because this comes out to
+//! only 32 bytes, [`ByteSet`] implements [`Copy`].
 //!
 //! ## Benchmarks
 //!
@@ -414,17 +415,51 @@
 //!
 //! This makes the following possible:
 //!
-//! ```rust,ignore
-//! use serde::{Serialize, Deserialize};
+//! ```rust
+//! # #[cfg(feature = "serde")]
+//! # fn main() {
+//! # use byte_set::ByteSet;
+//! use serde_derive::{Serialize, Deserialize};
 //!
 //! #[derive(Serialize, Deserialize)]
 //! struct MyValue {
 //!     bytes: ByteSet
 //! }
+//!
+//! let value = MyValue { bytes: ByteSet::ASCII_DIGIT };
+//! let json = serde_json::to_string(&value).unwrap();
+//! assert_eq!(json, r#"{"bytes":"0-9"}"#);
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
 //! ```
 //!
-//! [`ByteSet`] can be serialized into a `u8` sequence, and deserialized from
-//! `&[u8]` or a `u8` sequence.
+//! By default, [`ByteSet`] is serialized as a string in its text syntax, such as
+//! `"0-9 A-Z _ a-z"`, in human-readable formats like JSON, and as a 32-byte
+//! bitmap in binary formats like bincode. Other representations can be chosen
+//! per field with the [`byte_set::serde`] modules:
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")]
+//! # fn main() {
+//! # use byte_set::ByteSet;
+//! # use serde_derive::{Serialize, Deserialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct MyValue {
+//!     #[serde(with = "byte_set::serde::ranges")]
+//!     bytes: ByteSet
+//! }
+//!
+//! let value = MyValue { bytes: ByteSet::ASCII_DIGIT };
+//! let json = serde_json::to_string(&value).unwrap();
+//! assert_eq!(json, r#"{"bytes":[[48,57]]}"#);
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
+//! ```
+//!
+//! When deserializing from a self-describing format, every representation is
+//! accepted.
 //!
 //! Read more about using `serde` at [serde.rs](https://serde.rs/).
 //!
@@ -456,6 +491,7 @@
 //! [#3]: https://github.com/nvzqz/byte-set-rs/issues/3
 //!
 //! [`byte_set!`]:          macro.byte_set.html
+//! [`byte_set::serde`]:    serde/index.html
 //! [`ByteSet`]:            struct.ByteSet.html
 //! [`contains_any`]:       struct.ByteSet.html#method.contains_any
 //! [`contains`]:           struct.ByteSet.html#method.contains
@@ -489,7 +525,49 @@ pub(crate) mod chunk;
 pub(crate) use chunk::Chunk;
 
 mod byte_set;
-pub use self::byte_set::ByteSet;
+pub use self::byte_set::{
+    ByteSet, InvalidByte, ParseError, ParseErrorReason, Positions, RSplit,
+    Ranges, Split, SplitInclusive, SplitN, Tokens,
+};
 
 mod iter;
 pub use iter::Iter;
+
+mod searcher;
+pub use searcher::{ByteSearcher, FindIter};
+
+mod byte_classes;
+pub use byte_classes::ByteClasses;
+
+mod class_pattern;
+pub use class_pattern::{ClassMatches, ClassPattern};
+
+pub mod byte_map;
+#[doc(inline)]
+pub use byte_map::ByteMap;
+
+pub mod byte_counts;
+#[doc(inline)]
+pub use byte_counts::ByteCounts;
+
+pub mod ascii_set;
+#[doc(inline)]
+pub use ascii_set::AsciiSet;
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod byte_sparse_map;
+#[cfg(any(test, feature = "alloc"))]
+#[doc(inline)]
+pub use byte_sparse_map::ByteSparseMap;
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod byte_trie;
+#[cfg(any(test, feature = "alloc"))]
+#[doc(inline)]
+pub use byte_trie::ByteTrie;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
@@ -27,75 +27,51 @@ macro_rules! byte_set {
     };
 }
 
-/// Performs a `$map` operation over the `usize` chunks of `$this` and `$other`,
-/// combining the resulting `usize` chunks with `$reduce`.
+/// Performs a `$map` operation over the chunks of `$this` and `$other`,
+/// combining the resulting chunks with `$reduce`.
+///
+/// This loops over the chunks instead of naming each one, so that it works for
+/// any chunk width. The loop has a constant bound, so it is unrolled.
 macro_rules! map_reduce_chunks {
     ($this:expr, $other:expr, $map:tt, $reduce:tt) => {{
-        // TODO: Might be worth wrapping a `const fn`? This is only being used
-        // by binary ops, so this is fine for now.
-        #[cfg(target_pointer_width = "64")]
-        {
-            ($this.0[0] $map $other.0[0]) $reduce
-            ($this.0[1] $map $other.0[1]) $reduce
-            ($this.0[2] $map $other.0[2]) $reduce
-            ($this.0[3] $map $other.0[3])
-        }
+        let (this, other) = (&$this, &$other);
 
-        #[cfg(not(target_pointer_width = "64"))]
-        {
-            ($this.0[0] $map $other.0[0]) $reduce
-            ($this.0[1] $map $other.0[1]) $reduce
-            ($this.0[2] $map $other.0[2]) $reduce
-            ($this.0[3] $map $other.0[3]) $reduce
-            ($this.0[4] $map $other.0[4]) $reduce
-            ($this.0[5] $map $other.0[5]) $reduce
-            ($this.0[6] $map $other.0[6]) $reduce
-            ($this.0[7] $map $other.0[7])
+        let mut result = this.0[0] $map other.0[0];
+        let mut i = 1;
+        while i < ByteSet::NUM_SLOTS {
+            result = result $reduce (this.0[i] $map other.0[i]);
+            i += 1;
         }
+        result
     }};
 }
 
-/// Performs a `$map` operation over the `usize` chunks of `$this` and `$other`,
-/// returning the resulting `ByteSet`.
+/// Performs a `$map` operation over the chunks of `$this`, or of `$this` and
+/// `$other`, returning the resulting `ByteSet`.
+///
+/// This loops over the chunks instead of naming each one, so that it works for
+/// any chunk width. The loop has a constant bound, so it is unrolled.
 macro_rules! map_chunks {
     ($this:expr, $map:tt) => {{
-        // TODO: Might be worth wrapping a `const fn`? This is only being used
-        // by `!`, so being a prefix op is fine for now.
-        #[cfg(target_pointer_width = "64")]
-        {
-            ByteSet([
-                $map $this.0[0], $map $this.0[1],
-                $map $this.0[2], $map $this.0[3],
-            ])
-        }
+        let this = &$this;
 
-        #[cfg(not(target_pointer_width = "64"))]
-        {
-            ByteSet([
-                $map $this.0[0], $map $this.0[1],
-                $map $this.0[2], $map $this.0[3],
-                $map $this.0[4], $map $this.0[5],
-                $map $this.0[6], $map $this.0[7],
-            ])
+        let mut chunks = [0; ByteSet::NUM_SLOTS];
+        let mut i = 0;
+        while i < ByteSet::NUM_SLOTS {
+            chunks[i] = $map this.0[i];
+            i += 1;
         }
+        ByteSet(chunks)
     }};
     ($this:expr, $map:tt, $other:expr) => {{
-        #[cfg(target_pointer_width = "64")]
-        {
-            ByteSet([
-                ($this.0[0] $map $other.0[0]), ($this.0[1] $map $other.0[1]),
-                ($this.0[2] $map $other.0[2]), ($this.0[3] $map $other.0[3]),
-            ])
-        }
+        let (this, other) = (&$this, &$other);
 
-        #[cfg(not(target_pointer_width = "64"))]
-        {
-            ByteSet([
-                ($this.0[0] $map $other.0[0]), ($this.0[1] $map $other.0[1]),
-                ($this.0[2] $map $other.0[2]), ($this.0[3] $map $other.0[3]),
-                ($this.0[4] $map $other.0[4]), ($this.0[5] $map $other.0[5]),
-                ($this.0[6] $map $other.0[6]), ($this.0[7] $map $other.0[7]),
-            ])
+        let mut chunks = [0; ByteSet::NUM_SLOTS];
+        let mut i = 0;
+        while i < ByteSet::NUM_SLOTS {
+            chunks[i] = this.0[i] $map other.0[i];
+            i += 1;
         }
+        ByteSet(chunks)
     }};
 }
@@ -0,0 +1,67 @@
+//! NEON backend.
+
+use core::arch::aarch64::*;
+
+use super::vector::{Shuffle, Vector};
+
+impl Vector for uint8x16_t {
+    const LANES: usize = 16;
+
+    // There is no `movemask` instruction, so each lane is narrowed to 4 bits.
+    const MASK_STRIDE: u32 = 4;
+
+    #[inline(always)]
+    unsafe fn splat(byte: u8) -> Self {
+        vdupq_n_u8(byte)
+    }
+
+    #[inline(always)]
+    unsafe fn load_unaligned(ptr: *const u8) -> Self {
+        vld1q_u8(ptr)
+    }
+
+    #[inline(always)]
+    unsafe fn cmpeq(self, other: Self) -> Self {
+        vceqq_u8(self, other)
+    }
+
+    #[inline(always)]
+    unsafe fn or(self, other: Self) -> Self {
+        vorrq_u8(self, other)
+    }
+
+    #[inline(always)]
+    unsafe fn and(self, other: Self) -> Self {
+        vandq_u8(self, other)
+    }
+
+    #[inline(always)]
+    unsafe fn movemask(self) -> u64 {
+        let narrowed = vshrn_n_u16::<4>(vreinterpretq_u16_u8(self));
+        vget_lane_u64::<0>(vreinterpret_u64_u8(narrowed))
+    }
+}
+
+impl Shuffle for uint8x16_t {
+    #[inline(always)]
+    unsafe fn load_table(table: &[u8; 16]) -> Self {
+        vld1q_u8(table.as_ptr())
+    }
+
+    #[inline(always)]
+    unsafe fn high_nibbles(self) -> Self {
+        vshrq_n_u8::<4>(self)
+    }
+
+    #[inline(always)]
+    unsafe fn shuffle(self, indices: Self) -> Self {
+        vqtbl1q_u8(self, indices)
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero(self) -> Self {
+        vtstq_u8(self, self)
+    }
+}
+
+backend!(neon, "neon", uint8x16_t);
@@ -0,0 +1,36 @@
+//! The strategy a [`ByteSearcher`] uses for its set.
+//!
+//! [`ByteSearcher`]: ../struct.ByteSearcher.html
+
+/// The precompiled form of a [`ByteSet`] used by a [`ByteSearcher`].
+///
+/// [`ByteSet`]: ../struct.ByteSet.html
+/// [`ByteSearcher`]: ../struct.ByteSearcher.html
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Kernel {
+    /// The set is empty.
+    Empty,
+    /// Compare against one byte.
+    One(u8),
+    /// Compare against two bytes.
+    Two(u8, u8),
+    /// Compare against three bytes.
+    Three(u8, u8, u8),
+    /// Look up the low nibble of each byte to get a bit mask of high nibbles.
+    #[cfg(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        all(target_arch = "aarch64", target_feature = "neon"),
+    ))]
+    Nibbles(super::vector::NibbleTables),
+    /// Look up each byte in the set.
+    Table,
+}
+
+impl Kernel {
+    /// Returns `true` if this kernel has a vectorized implementation.
+    #[inline]
+    pub fn is_vectorized(&self) -> bool {
+        !matches!(self, Kernel::Empty | Kernel::Table)
+    }
+}
@@ -0,0 +1,370 @@
+use crate::ByteSet;
+use core::{fmt, iter};
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    all(target_arch = "aarch64", target_feature = "neon"),
+))]
+#[macro_use]
+mod vector;
+
+mod kernel;
+pub(crate) use kernel::Kernel;
+
+pub(crate) mod word;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod aarch64;
+
+/// A [`ByteSet`] compiled for fast searching of byte slices.
+///
+/// Creating a searcher chooses the fastest strategy for the shape of the set
+/// and the current CPU:
+///
+/// - Sets of 1 to 3 bytes broadcast each byte into a vector and compare it
+///   against the haystack, like [`memchr`].
+///
+/// - Any other set is classified by using the low and high nibbles of each
+///   byte as indices into 16-byte tables with a byte shuffle instruction
+///   (`pshufb` on SSSE3 and AVX2, `tbl` on NEON).
+///
+/// - If no vector instructions are available, or the haystack is too short to
+///   fill a vector, it is read 8 bytes at a time into a `u64`. Sets of 1 to 3
+///   bytes are compared against the whole word with bit tricks, and other sets
+///   look up each byte of the word and pack the results into a mask.
+///
+/// With the `std` feature enabled, the available instructions on x86 are
+/// detected at runtime. Otherwise, only those enabled at compile time are used.
+///
+/// Because creating a searcher does some work up front, it should be reused
+/// across searches. For one-off searches, consider [`ByteSet::find_in`]
+/// instead.
+///
+/// # Examples
+///
+/// ```
+/// use byte_set::{ByteSearcher, ByteSet};
+///
+/// let searcher = ByteSearcher::new(ByteSet::ASCII_WHITESPACE);
+/// let haystack = b"hello world\n";
+///
+/// assert_eq!(searcher.find(haystack), Some(5));
+/// assert_eq!(searcher.rfind(haystack), Some(11));
+/// assert_eq!(searcher.count(haystack), 2);
+/// ```
+///
+/// [`ByteSet`]: struct.ByteSet.html
+/// [`ByteSet::find_in`]: struct.ByteSet.html#method.find_in
+/// [`memchr`]: https://docs.rs/memchr
+#[derive(Clone)]
+pub struct ByteSearcher {
+    set: ByteSet,
+    kernel: Kernel,
+    isa: Isa,
+    table: [bool; 256],
+}
+
+/// The length of the narrowest vector of any backend. Shorter haystacks are
+/// always searched a word at a time.
+pub(crate) const MIN_VECTOR_LEN: usize = 16;
+
+/// The instruction set used for searching.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Isa {
+    Scalar,
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Sse2,
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Ssse3,
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Avx2,
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    Neon,
+}
+
+impl Isa {
+    /// Returns the best instruction set supported by the current CPU.
+    #[allow(unreachable_code)]
+    fn detect() -> Self {
+        #[cfg(all(
+            any(test, feature = "std"),
+            any(target_arch = "x86", target_arch = "x86_64"),
+        ))]
+        {
+            if is_x86_feature_detected!("avx2") {
+                return Isa::Avx2;
+            } else if is_x86_feature_detected!("ssse3") {
+                return Isa::Ssse3;
+            } else if is_x86_feature_detected!("sse2") {
+                return Isa::Sse2;
+            }
+        }
+
+        #[cfg(all(
+            not(any(test, feature = "std")),
+            any(target_arch = "x86", target_arch = "x86_64"),
+        ))]
+        {
+            if cfg!(target_feature = "avx2") {
+                return Isa::Avx2;
+            } else if cfg!(target_feature = "ssse3") {
+                return Isa::Ssse3;
+            } else if cfg!(target_feature = "sse2") {
+                return Isa::Sse2;
+            }
+        }
+
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        return Isa::Neon;
+
+        Isa::Scalar
+    }
+
+    /// Returns `true` if a byte shuffle instruction is available.
+    #[cfg(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        all(target_arch = "aarch64", target_feature = "neon"),
+    ))]
+    fn has_shuffle(self) -> bool {
+        match self {
+            Isa::Scalar => false,
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Isa::Sse2 => false,
+            #[allow(unreachable_patterns)]
+            _ => true,
+        }
+    }
+}
+
+/// Calls `$op` from the widest vector backend that fits `$haystack`, or
+/// evaluates `$fallback` if there is none.
+macro_rules! dispatch {
+    ($self:ident, $haystack:ident, $op:ident, $fallback:expr) => {
+        // SAFETY: `isa` is only vectorized if it was detected and the kernel
+        // is vectorized, and each backend is only called for haystacks that
+        // fill at least one vector.
+        match $self.isa {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Isa::Avx2 if $haystack.len() >= 32 => unsafe {
+                x86::avx2::$op(&$self.kernel, $haystack)
+            },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Isa::Avx2 | Isa::Ssse3 if $haystack.len() >= 16 => unsafe {
+                x86::ssse3::$op(&$self.kernel, $haystack)
+            },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Isa::Sse2 if $haystack.len() >= 16 => unsafe {
+                x86::sse2::$op(&$self.kernel, $haystack)
+            },
+            #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+            Isa::Neon if $haystack.len() >= 16 => unsafe {
+                aarch64::neon::$op(&$self.kernel, $haystack)
+            },
+            _ => $fallback,
+        }
+    };
+}
+
+impl ByteSearcher {
+    /// Compiles `set` into a searcher using the best strategy available.
+    pub fn new(set: ByteSet) -> Self {
+        Self::with_isa(set, Isa::detect())
+    }
+
+    /// Compiles `set` into a searcher that uses at most `isa`.
+    pub(crate) fn with_isa(set: ByteSet, isa: Isa) -> Self {
+        let mut table = [false; 256];
+        for byte in set {
+            table[byte as usize] = true;
+        }
+
+        let mut bytes = set.into_iter();
+        let kernel = match (set.len(), isa) {
+            (0, _) => Kernel::Empty,
+            (1, _) => Kernel::One(bytes.next().unwrap()),
+            (2, _) => Kernel::Two(bytes.next().unwrap(), bytes.next().unwrap()),
+            (3, _) => Kernel::Three(
+                bytes.next().unwrap(),
+                bytes.next().unwrap(),
+                bytes.next().unwrap(),
+            ),
+            #[cfg(any(
+                target_arch = "x86",
+                target_arch = "x86_64",
+                all(target_arch = "aarch64", target_feature = "neon"),
+            ))]
+            _ if isa.has_shuffle() => {
+                Kernel::Nibbles(vector::NibbleTables::new(set))
+            }
+            _ => Kernel::Table,
+        };
+
+        let isa = if kernel.is_vectorized() {
+            isa
+        } else {
+            Isa::Scalar
+        };
+
+        Self {
+            set,
+            kernel,
+            isa,
+            table,
+        }
+    }
+
+    /// Returns the set being searched for.
+    #[inline]
+    pub const fn byte_set(&self) -> ByteSet {
+        self.set
+    }
+
+    /// Returns the index of the first byte in `haystack` that is contained in
+    /// the set, or `None` if there is none.
+    #[must_use]
+    pub fn find(&self, haystack: &[u8]) -> Option<usize> {
+        dispatch!(self, haystack, find, {
+            word::find(&self.kernel, |b| self.table[b as usize], haystack)
+        })
+    }
+
+    /// Returns the index of the last byte in `haystack` that is contained in
+    /// the set, or `None` if there is none.
+    #[must_use]
+    pub fn rfind(&self, haystack: &[u8]) -> Option<usize> {
+        dispatch!(self, haystack, rfind, {
+            word::rfind(&self.kernel, |b| self.table[b as usize], haystack)
+        })
+    }
+
+    /// Returns the number of bytes in `haystack` that are contained in the
+    /// set.
+    #[must_use]
+    pub fn count(&self, haystack: &[u8]) -> usize {
+        dispatch!(self, haystack, count, {
+            word::count(&self.kernel, |b| self.table[b as usize], haystack)
+        })
+    }
+
+    /// Returns an iterator over the indices of all bytes in `haystack` that are
+    /// contained in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_set::{ByteSearcher, ByteSet};
+    ///
+    /// let searcher = ByteSearcher::new(ByteSet::ASCII_DIGIT);
+    /// let indices: Vec<usize> = searcher.find_iter(b"a1b22c").collect();
+    ///
+    /// assert_eq!(indices, [1, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn find_iter<'s, 'h>(&'s self, haystack: &'h [u8]) -> FindIter<'s, 'h> {
+        FindIter {
+            searcher: self,
+            haystack,
+            start: 0,
+            end: haystack.len(),
+        }
+    }
+}
+
+impl From<ByteSet> for ByteSearcher {
+    #[inline]
+    fn from(set: ByteSet) -> Self {
+        Self::new(set)
+    }
+}
+
+impl fmt::Debug for ByteSearcher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ByteSearcher")
+            .field("set", &self.set)
+            .field("kernel", &self.kernel)
+            .field("isa", &self.isa)
+            .finish()
+    }
+}
+
+/// An iterator over the indices of bytes matched by a [`ByteSearcher`].
+///
+/// This is created by [`ByteSearcher::find_iter`].
+///
+/// [`ByteSearcher`]: struct.ByteSearcher.html
+/// [`ByteSearcher::find_iter`]: struct.ByteSearcher.html#method.find_iter
+#[derive(Clone, Debug)]
+pub struct FindIter<'s, 'h> {
+    searcher: &'s ByteSearcher,
+    haystack: &'h [u8],
+
+    /// The start of the unsearched part of `haystack`.
+    start: usize,
+
+    /// The end of the unsearched part of `haystack`.
+    end: usize,
+}
+
+impl Iterator for FindIter<'_, '_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let rest = &self.haystack[self.start..self.end];
+        let index = self.start + self.searcher.find(rest)?;
+
+        self.start = index + 1;
+        Some(index)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.end - self.start))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.searcher.count(&self.haystack[self.start..self.end])
+    }
+}
+
+impl DoubleEndedIterator for FindIter<'_, '_> {
+    fn next_back(&mut self) -> Option<usize> {
+        let rest = &self.haystack[self.start..self.end];
+        let index = self.start + self.searcher.rfind(rest)?;
+
+        self.end = index;
+        Some(index)
+    }
+}
+
+// `FindIter` does not produce more values after `None` is reached.
+impl iter::FusedIterator for FindIter<'_, '_> {}
+
+#[cfg(test)]
+impl Isa {
+    /// Returns all instruction sets supported by the current CPU.
+    pub(crate) fn all_detected() -> Vec<Self> {
+        let best = Self::detect();
+
+        let all = [
+            Isa::Scalar,
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Isa::Sse2,
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Isa::Ssse3,
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Isa::Avx2,
+            #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+            Isa::Neon,
+        ];
+
+        // Instruction sets are listed from worst to best.
+        let supported = all.iter().position(|&isa| isa == best).unwrap() + 1;
+        all[..supported].to_vec()
+    }
+}
@@ -0,0 +1,383 @@
+//! Vector operations shared by all SIMD backends.
+//!
+//! Each backend implements [`Vector`] (and [`Shuffle`] if it has a byte
+//! shuffle instruction) for its native vector type. The search loops here are
+//! generic over these traits and marked `#[inline(always)]`, so that they get
+//! compiled with the target features of the backend function that calls them.
+
+use crate::ByteSet;
+
+/// A vector of bytes.
+pub(crate) trait Vector: Copy {
+    /// The number of bytes in the vector.
+    const LANES: usize;
+
+    /// The number of bits each lane occupies in the result of `movemask`.
+    const MASK_STRIDE: u32;
+
+    unsafe fn splat(byte: u8) -> Self;
+
+    unsafe fn load_unaligned(ptr: *const u8) -> Self;
+
+    unsafe fn cmpeq(self, other: Self) -> Self;
+
+    unsafe fn or(self, other: Self) -> Self;
+
+    unsafe fn and(self, other: Self) -> Self;
+
+    /// Packs lanes that are `0xFF` into a bit mask with `MASK_STRIDE` bits per
+    /// lane. All lanes must be either `0x00` or `0xFF`.
+    unsafe fn movemask(self) -> u64;
+}
+
+/// A vector that can be used as a 16-entry lookup table.
+pub(crate) trait Shuffle: Vector {
+    /// Loads `table` into every 16-byte lane of the vector.
+    unsafe fn load_table(table: &[u8; 16]) -> Self;
+
+    /// Returns the high nibble of each byte.
+    unsafe fn high_nibbles(self) -> Self;
+
+    /// Looks up each byte of `indices` in `self`. All indices must be less
+    /// than 16.
+    unsafe fn shuffle(self, indices: Self) -> Self;
+
+    /// Sets each nonzero lane to `0xFF`.
+    unsafe fn nonzero(self) -> Self;
+}
+
+/// Classifies each byte in a vector as either a match (`0xFF`) or not
+/// (`0x00`).
+pub(crate) trait Classify<V: Vector> {
+    unsafe fn classify(&self, vector: V) -> V;
+}
+
+/// Lookup tables indexed by the low nibble of a byte.
+///
+/// Entry `lo` in `low` has bit `hi` enabled if `hi << 4 | lo` is in the set,
+/// where `hi` is less than 8. Likewise, `high` holds the bits for `hi` of 8 and
+/// above.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct NibbleTables {
+    low: [u8; 16],
+    high: [u8; 16],
+}
+
+impl NibbleTables {
+    /// Bits selected by high nibbles less than 8.
+    const BITS_LOW: [u8; 16] = [
+        1, 2, 4, 8, 16, 32, 64, 128, 0, 0, 0, 0, 0, 0, 0, 0, //
+    ];
+
+    /// Bits selected by high nibbles of 8 and above.
+    const BITS_HIGH: [u8; 16] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 4, 8, 16, 32, 64, 128, //
+    ];
+
+    pub fn new(set: ByteSet) -> Self {
+        let mut tables = Self {
+            low: [0; 16],
+            high: [0; 16],
+        };
+
+        for byte in set {
+            let lo = (byte & 0xF) as usize;
+            let hi = byte >> 4;
+
+            if hi < 8 {
+                tables.low[lo] |= 1 << hi;
+            } else {
+                tables.high[lo] |= 1 << (hi - 8);
+            }
+        }
+
+        tables
+    }
+}
+
+pub(crate) struct Bytes1<V>(V);
+
+pub(crate) struct Bytes2<V>(V, V);
+
+pub(crate) struct Bytes3<V>(V, V, V);
+
+pub(crate) struct Nibbles<V> {
+    low: V,
+    high: V,
+    bits_low: V,
+    bits_high: V,
+    low_nibble: V,
+}
+
+impl<V: Vector> Bytes1<V> {
+    #[inline(always)]
+    pub unsafe fn new(a: u8) -> Self {
+        Self(V::splat(a))
+    }
+}
+
+impl<V: Vector> Bytes2<V> {
+    #[inline(always)]
+    pub unsafe fn new(a: u8, b: u8) -> Self {
+        Self(V::splat(a), V::splat(b))
+    }
+}
+
+impl<V: Vector> Bytes3<V> {
+    #[inline(always)]
+    pub unsafe fn new(a: u8, b: u8, c: u8) -> Self {
+        Self(V::splat(a), V::splat(b), V::splat(c))
+    }
+}
+
+impl<V: Shuffle> Nibbles<V> {
+    #[inline(always)]
+    pub unsafe fn new(tables: &NibbleTables) -> Self {
+        Self {
+            low: V::load_table(&tables.low),
+            high: V::load_table(&tables.high),
+            bits_low: V::load_table(&NibbleTables::BITS_LOW),
+            bits_high: V::load_table(&NibbleTables::BITS_HIGH),
+            low_nibble: V::splat(0xF),
+        }
+    }
+}
+
+impl<V: Vector> Classify<V> for Bytes1<V> {
+    #[inline(always)]
+    unsafe fn classify(&self, vector: V) -> V {
+        vector.cmpeq(self.0)
+    }
+}
+
+impl<V: Vector> Classify<V> for Bytes2<V> {
+    #[inline(always)]
+    unsafe fn classify(&self, vector: V) -> V {
+        vector.cmpeq(self.0).or(vector.cmpeq(self.1))
+    }
+}
+
+impl<V: Vector> Classify<V> for Bytes3<V> {
+    #[inline(always)]
+    unsafe fn classify(&self, vector: V) -> V {
+        vector
+            .cmpeq(self.0)
+            .or(vector.cmpeq(self.1))
+            .or(vector.cmpeq(self.2))
+    }
+}
+
+impl<V: Shuffle> Classify<V> for Nibbles<V> {
+    #[inline(always)]
+    unsafe fn classify(&self, vector: V) -> V {
+        let lo = vector.and(self.low_nibble);
+        let hi = vector.high_nibbles();
+
+        let low = self.low.shuffle(lo).and(self.bits_low.shuffle(hi));
+        let high = self.high.shuffle(lo).and(self.bits_high.shuffle(hi));
+
+        low.or(high).nonzero()
+    }
+}
+
+#[inline(always)]
+fn first_lane<V: Vector>(mask: u64) -> usize {
+    (mask.trailing_zeros() / V::MASK_STRIDE) as usize
+}
+
+#[inline(always)]
+fn last_lane<V: Vector>(mask: u64) -> usize {
+    ((63 ^ mask.leading_zeros()) / V::MASK_STRIDE) as usize
+}
+
+/// Returns a mask of the lanes before `lanes`, which must be less than
+/// `V::LANES`.
+#[inline(always)]
+fn lanes_before<V: Vector>(lanes: usize) -> u64 {
+    (1 << (lanes as u32 * V::MASK_STRIDE)) - 1
+}
+
+#[inline(always)]
+unsafe fn mask_at<V: Vector, C: Classify<V>>(
+    classifier: &C,
+    haystack: &[u8],
+    offset: usize,
+) -> u64 {
+    debug_assert!(offset + V::LANES <= haystack.len());
+
+    let vector = V::load_unaligned(haystack.as_ptr().add(offset));
+    classifier.classify(vector).movemask()
+}
+
+/// Returns the index of the first match in `haystack`, which must be at least
+/// `V::LANES` long.
+#[inline(always)]
+pub(crate) unsafe fn find<V: Vector, C: Classify<V>>(
+    classifier: &C,
+    haystack: &[u8],
+) -> Option<usize> {
+    let len = haystack.len();
+    let mut offset = 0;
+
+    while offset + V::LANES <= len {
+        let mask = mask_at(classifier, haystack, offset);
+        if mask != 0 {
+            return Some(offset + first_lane::<V>(mask));
+        }
+        offset += V::LANES;
+    }
+
+    if offset < len {
+        // Reprocess an overlapping vector at the end and ignore the lanes that
+        // were already checked.
+        let start = len - V::LANES;
+        let skip = (offset - start) as u32 * V::MASK_STRIDE;
+
+        let mask = mask_at(classifier, haystack, start) >> skip;
+        if mask != 0 {
+            return Some(offset + first_lane::<V>(mask));
+        }
+    }
+
+    None
+}
+
+/// Returns the index of the last match in `haystack`, which must be at least
+/// `V::LANES` long.
+#[inline(always)]
+pub(crate) unsafe fn rfind<V: Vector, C: Classify<V>>(
+    classifier: &C,
+    haystack: &[u8],
+) -> Option<usize> {
+    let mut end = haystack.len();
+
+    while end >= V::LANES {
+        let start = end - V::LANES;
+
+        let mask = mask_at(classifier, haystack, start);
+        if mask != 0 {
+            return Some(start + last_lane::<V>(mask));
+        }
+        end = start;
+    }
+
+    if end > 0 {
+        // Reprocess an overlapping vector at the start and ignore the lanes
+        // that were already checked.
+        let mask = mask_at(classifier, haystack, 0) & lanes_before::<V>(end);
+        if mask != 0 {
+            return Some(last_lane::<V>(mask));
+        }
+    }
+
+    None
+}
+
+/// Returns the number of matches in `haystack`, which must be at least
+/// `V::LANES` long.
+#[inline(always)]
+pub(crate) unsafe fn count<V: Vector, C: Classify<V>>(
+    classifier: &C,
+    haystack: &[u8],
+) -> usize {
+    let len = haystack.len();
+    let mut offset = 0;
+    let mut bits = 0;
+
+    while offset + V::LANES <= len {
+        bits += mask_at(classifier, haystack, offset).count_ones() as usize;
+        offset += V::LANES;
+    }
+
+    if offset < len {
+        let start = len - V::LANES;
+        let skip = (offset - start) as u32 * V::MASK_STRIDE;
+
+        let mask = mask_at(classifier, haystack, start) >> skip;
+        bits += mask.count_ones() as usize;
+    }
+
+    bits / V::MASK_STRIDE as usize
+}
+
+/// Creates the classifier for `$kernel` over `$vector` as `$c` and evaluates
+/// `$body` with it.
+///
+/// The `bytes` form is for backends without [`Shuffle`].
+macro_rules! with_classifier {
+    (bytes $vector:ty, $kernel:expr, |$c:ident| $body:expr) => {
+        match *$kernel {
+            Kernel::One(a) => {
+                let $c = Bytes1::<$vector>::new(a);
+                $body
+            }
+            Kernel::Two(a, b) => {
+                let $c = Bytes2::<$vector>::new(a, b);
+                $body
+            }
+            Kernel::Three(a, b, c) => {
+                let $c = Bytes3::<$vector>::new(a, b, c);
+                $body
+            }
+            _ => unreachable!("{:?} is not vectorized", $kernel),
+        }
+    };
+
+    ($vector:ty, $kernel:expr, |$c:ident| $body:expr) => {
+        match *$kernel {
+            Kernel::Nibbles(ref tables) => {
+                let $c = Nibbles::<$vector>::new(tables);
+                $body
+            }
+            _ => with_classifier!(bytes $vector, $kernel, |$c| $body),
+        }
+    };
+}
+
+/// Defines a module named `$name` with `find`, `rfind`, and `count` functions
+/// compiled with `$feature` enabled.
+///
+/// Callers must ensure that `$feature` is supported by the current CPU, that
+/// the kernel is vectorized, and that the haystack is at least as long as
+/// `$vector`.
+macro_rules! backend {
+    ($name:ident, $feature:literal, $vector:ty $(, $bytes:ident)?) => {
+        pub(crate) mod $name {
+            use super::*;
+            use crate::searcher::{
+                vector::{self, *},
+                Kernel,
+            };
+
+            #[target_feature(enable = $feature)]
+            pub(crate) unsafe fn find(
+                kernel: &Kernel,
+                haystack: &[u8],
+            ) -> Option<usize> {
+                with_classifier!($($bytes)? $vector, kernel, |c| {
+                    vector::find(&c, haystack)
+                })
+            }
+
+            #[target_feature(enable = $feature)]
+            pub(crate) unsafe fn rfind(
+                kernel: &Kernel,
+                haystack: &[u8],
+            ) -> Option<usize> {
+                with_classifier!($($bytes)? $vector, kernel, |c| {
+                    vector::rfind(&c, haystack)
+                })
+            }
+
+            #[target_feature(enable = $feature)]
+            pub(crate) unsafe fn count(
+                kernel: &Kernel,
+                haystack: &[u8],
+            ) -> usize {
+                with_classifier!($($bytes)? $vector, kernel, |c| {
+                    vector::count(&c, haystack)
+                })
+            }
+        }
+    };
+}
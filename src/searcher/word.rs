@@ -0,0 +1,194 @@
+//! Word-at-a-time search, used when no vector backend applies.
+//!
+//! The haystack is read eight bytes at a time into a `u64`. Each word is
+//! classified into a mask with the high bit of each matching byte enabled, so
+//! that a whole word is checked with a single branch and the first or last
+//! match is found with `trailing_zeros` or `leading_zeros`.
+
+use super::Kernel;
+use core::convert::TryInto;
+
+const WORD_LEN: usize = 8;
+
+/// The low bit of each byte.
+const LOW_BITS: u64 = 0x0101_0101_0101_0101;
+
+/// The high bit of each byte.
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+
+/// Returns a mask with the high bit of each zero byte in `word` enabled.
+///
+/// Unlike the `(x - 0x01..) & !x & 0x80..` trick, this is exact for every byte
+/// rather than only the first zero byte, so it also works for `rfind` and
+/// `count`.
+#[inline(always)]
+fn zero_bytes(word: u64) -> u64 {
+    let low = !HIGH_BITS;
+    !(((word & low).wrapping_add(low)) | word | low)
+}
+
+/// Returns a mask with the high bit of each byte in `word` equal to `byte`
+/// enabled.
+#[inline(always)]
+fn eq_bytes(word: u64, byte: u8) -> u64 {
+    zero_bytes(word ^ (LOW_BITS * byte as u64))
+}
+
+/// Returns a mask with the high bit of each byte in `word` that `contains`
+/// enabled, without branching on any byte.
+#[inline(always)]
+fn lookup_bytes(word: u64, contains: &impl Fn(u8) -> bool) -> u64 {
+    let mut mask = 0;
+    for (i, &byte) in word.to_le_bytes().iter().enumerate() {
+        mask |= (contains(byte) as u64) << (i * 8);
+    }
+    mask << 7
+}
+
+/// Reads up to 8 bytes of `bytes` into a word, with missing bytes set to 0.
+#[inline(always)]
+fn read(bytes: &[u8]) -> u64 {
+    match bytes.try_into() {
+        Ok(word) => u64::from_le_bytes(word),
+        // Folding avoids a `memcpy` call for the variable length.
+        Err(_) => bytes.iter().rev().fold(0, |word, &b| word << 8 | b as u64),
+    }
+}
+
+/// Returns a mask of the bytes before `len`, which must be less than 8.
+#[inline(always)]
+fn bytes_before(len: usize) -> u64 {
+    (1 << (len * 8)) - 1
+}
+
+#[inline(always)]
+fn first_byte(mask: u64) -> usize {
+    (mask.trailing_zeros() / 8) as usize
+}
+
+#[inline(always)]
+fn last_byte(mask: u64) -> usize {
+    ((63 ^ mask.leading_zeros()) / 8) as usize
+}
+
+/// Returns the index of the first match of `classify` in `haystack`.
+#[inline(always)]
+fn find_with(haystack: &[u8], classify: impl Fn(u64) -> u64) -> Option<usize> {
+    let mut words = haystack.chunks_exact(WORD_LEN);
+    let mut offset = 0;
+
+    for word in &mut words {
+        let mask = classify(read(word));
+        if mask != 0 {
+            return Some(offset + first_byte(mask));
+        }
+        offset += WORD_LEN;
+    }
+
+    let rest = words.remainder();
+    let mask = classify(read(rest)) & bytes_before(rest.len());
+    if mask != 0 {
+        Some(offset + first_byte(mask))
+    } else {
+        None
+    }
+}
+
+/// Returns the index of the last match of `classify` in `haystack`.
+#[inline(always)]
+fn rfind_with(haystack: &[u8], classify: impl Fn(u64) -> u64) -> Option<usize> {
+    let mut words = haystack.rchunks_exact(WORD_LEN);
+    let mut end = haystack.len();
+
+    for word in &mut words {
+        end -= WORD_LEN;
+
+        let mask = classify(read(word));
+        if mask != 0 {
+            return Some(end + last_byte(mask));
+        }
+    }
+
+    let rest = words.remainder();
+    let mask = classify(read(rest)) & bytes_before(rest.len());
+    if mask != 0 {
+        Some(last_byte(mask))
+    } else {
+        None
+    }
+}
+
+/// Returns the number of matches of `classify` in `haystack`.
+#[inline(always)]
+fn count_with(haystack: &[u8], classify: impl Fn(u64) -> u64) -> usize {
+    let mut words = haystack.chunks_exact(WORD_LEN);
+    let mut count = 0;
+
+    for word in &mut words {
+        count += classify(read(word)).count_ones() as usize;
+    }
+
+    let rest = words.remainder();
+    let mask = classify(read(rest)) & bytes_before(rest.len());
+    count + mask.count_ones() as usize
+}
+
+/// Evaluates `$body` with `$c` bound to the word classifier for `$kernel`.
+macro_rules! with_word_classifier {
+    ($kernel:expr, $contains:expr, |$c:ident| $body:expr) => {
+        match *$kernel {
+            Kernel::Empty => {
+                let $c = |_| 0;
+                $body
+            }
+            Kernel::One(a) => {
+                let $c = |word| eq_bytes(word, a);
+                $body
+            }
+            Kernel::Two(a, b) => {
+                let $c = |word| eq_bytes(word, a) | eq_bytes(word, b);
+                $body
+            }
+            Kernel::Three(a, b, c) => {
+                let $c = |word| {
+                    eq_bytes(word, a) | eq_bytes(word, b) | eq_bytes(word, c)
+                };
+                $body
+            }
+            _ => {
+                let contains = $contains;
+                let $c = |word| lookup_bytes(word, &contains);
+                $body
+            }
+        }
+    };
+}
+
+/// Returns the index of the first byte in `haystack` matched by `kernel`.
+///
+/// Kernels without a word-level comparison look up each byte with `contains`.
+pub(crate) fn find(
+    kernel: &Kernel,
+    contains: impl Fn(u8) -> bool,
+    haystack: &[u8],
+) -> Option<usize> {
+    with_word_classifier!(kernel, contains, |c| find_with(haystack, c))
+}
+
+/// Returns the index of the last byte in `haystack` matched by `kernel`.
+pub(crate) fn rfind(
+    kernel: &Kernel,
+    contains: impl Fn(u8) -> bool,
+    haystack: &[u8],
+) -> Option<usize> {
+    with_word_classifier!(kernel, contains, |c| rfind_with(haystack, c))
+}
+
+/// Returns the number of bytes in `haystack` matched by `kernel`.
+pub(crate) fn count(
+    kernel: &Kernel,
+    contains: impl Fn(u8) -> bool,
+    haystack: &[u8],
+) -> usize {
+    with_word_classifier!(kernel, contains, |c| count_with(haystack, c))
+}
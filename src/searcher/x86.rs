@@ -0,0 +1,136 @@
+//! SSE2, SSSE3, and AVX2 backends.
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use super::vector::{Shuffle, Vector};
+
+impl Vector for __m128i {
+    const LANES: usize = 16;
+    const MASK_STRIDE: u32 = 1;
+
+    #[inline(always)]
+    unsafe fn splat(byte: u8) -> Self {
+        _mm_set1_epi8(byte as i8)
+    }
+
+    #[inline(always)]
+    unsafe fn load_unaligned(ptr: *const u8) -> Self {
+        _mm_loadu_si128(ptr.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn cmpeq(self, other: Self) -> Self {
+        _mm_cmpeq_epi8(self, other)
+    }
+
+    #[inline(always)]
+    unsafe fn or(self, other: Self) -> Self {
+        _mm_or_si128(self, other)
+    }
+
+    #[inline(always)]
+    unsafe fn and(self, other: Self) -> Self {
+        _mm_and_si128(self, other)
+    }
+
+    #[inline(always)]
+    unsafe fn movemask(self) -> u64 {
+        _mm_movemask_epi8(self) as u32 as u64
+    }
+}
+
+// Requires SSSE3.
+impl Shuffle for __m128i {
+    #[inline(always)]
+    unsafe fn load_table(table: &[u8; 16]) -> Self {
+        _mm_loadu_si128(table.as_ptr().cast())
+    }
+
+    #[inline(always)]
+    unsafe fn high_nibbles(self) -> Self {
+        _mm_and_si128(_mm_srli_epi16::<4>(self), _mm_set1_epi8(0xF))
+    }
+
+    #[inline(always)]
+    unsafe fn shuffle(self, indices: Self) -> Self {
+        _mm_shuffle_epi8(self, indices)
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero(self) -> Self {
+        let is_zero = _mm_cmpeq_epi8(self, _mm_setzero_si128());
+        _mm_xor_si128(is_zero, _mm_set1_epi8(-1))
+    }
+}
+
+// Requires AVX2.
+impl Vector for __m256i {
+    const LANES: usize = 32;
+    const MASK_STRIDE: u32 = 1;
+
+    #[inline(always)]
+    unsafe fn splat(byte: u8) -> Self {
+        _mm256_set1_epi8(byte as i8)
+    }
+
+    #[inline(always)]
+    unsafe fn load_unaligned(ptr: *const u8) -> Self {
+        _mm256_loadu_si256(ptr.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn cmpeq(self, other: Self) -> Self {
+        _mm256_cmpeq_epi8(self, other)
+    }
+
+    #[inline(always)]
+    unsafe fn or(self, other: Self) -> Self {
+        _mm256_or_si256(self, other)
+    }
+
+    #[inline(always)]
+    unsafe fn and(self, other: Self) -> Self {
+        _mm256_and_si256(self, other)
+    }
+
+    #[inline(always)]
+    unsafe fn movemask(self) -> u64 {
+        _mm256_movemask_epi8(self) as u32 as u64
+    }
+}
+
+// Requires AVX2.
+impl Shuffle for __m256i {
+    #[inline(always)]
+    unsafe fn load_table(table: &[u8; 16]) -> Self {
+        _mm256_broadcastsi128_si256(_mm_loadu_si128(table.as_ptr().cast()))
+    }
+
+    #[inline(always)]
+    unsafe fn high_nibbles(self) -> Self {
+        _mm256_and_si256(_mm256_srli_epi16::<4>(self), _mm256_set1_epi8(0xF))
+    }
+
+    #[inline(always)]
+    unsafe fn shuffle(self, indices: Self) -> Self {
+        // Shuffles within each 128-bit lane, which is why tables are loaded
+        // into both halves.
+        _mm256_shuffle_epi8(self, indices)
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero(self) -> Self {
+        let is_zero = _mm256_cmpeq_epi8(self, _mm256_setzero_si256());
+        _mm256_xor_si256(is_zero, _mm256_set1_epi8(-1))
+    }
+}
+
+// SSE2 has no byte shuffle, so it only handles 1-3 bytes.
+backend!(sse2, "sse2", __m128i, bytes);
+
+backend!(ssse3, "ssse3", __m128i);
+
+backend!(avx2, "avx2", __m256i);
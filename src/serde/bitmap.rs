@@ -0,0 +1,63 @@
+//! Serializes a [`ByteSet`] as its 32-byte [little-endian bitmap].
+//!
+//! This is the most compact representation for binary formats. It always
+//! takes 32 bytes plus whatever length prefix the format adds, regardless of
+//! how many bytes the set contains. It is the default for binary formats, and
+//! this module can be used to write it from human-readable formats too.
+//!
+//! When deserializing from a self-describing format, a sequence of 32 integers
+//! is read as the bitmap, and the [`ranges`] and [`text`] representations are
+//! also accepted. A sequence is read as [`ranges`] if its first element is a
+//! `[start, end]` pair, and an empty sequence is the empty set.
+//!
+//! # Examples
+//!
+//! ```
+//! use byte_set::{byte_set, ByteSet};
+//! use serde_derive::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Delimiters {
+//!     #[serde(with = "byte_set::serde::bitmap")]
+//!     bytes: ByteSet,
+//! }
+//!
+//! let delimiters = Delimiters {
+//!     bytes: byte_set![0, 255],
+//! };
+//! let json = serde_json::to_string(&delimiters).unwrap();
+//!
+//! let mut bitmap = [0; 32];
+//! bitmap[0] = 0b1;
+//! bitmap[31] = 0b1000_0000;
+//! assert_eq!(json, format!(r#"{{"bytes":{:?}}}"#, bitmap).replace(' ', ""));
+//!
+//! let read: Delimiters = serde_json::from_str(&json).unwrap();
+//! assert_eq!(read.bytes, delimiters.bytes);
+//! ```
+//!
+//! [`ByteSet`]:            ../../struct.ByteSet.html
+//! [little-endian bitmap]: ../../struct.ByteSet.html#method.to_bitmap_le
+//! [`ranges`]:             ../ranges/index.html
+//! [`text`]:               ../text/index.html
+
+use super::Form;
+use crate::ByteSet;
+use ::serde::{Deserializer, Serializer};
+
+/// Serializes `set` as its 32-byte little-endian bitmap.
+pub fn serialize<S>(set: &ByteSet, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_bytes(&set.to_bitmap_le())
+}
+
+/// Deserializes a set from its 32-byte little-endian bitmap, or from any other
+/// representation in self-describing formats.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<ByteSet, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    super::deserialize(deserializer, Form::Bitmap)
+}
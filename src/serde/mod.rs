@@ -0,0 +1,297 @@
+//! Alternative [`serde`] representations of [`ByteSet`].
+//!
+//! Each module here can be used with `#[serde(with = "...")]` to pick how a
+//! [`ByteSet`] field is written:
+//!
+//! | Module       | Representation                                     |
+//! | ------------ | -------------------------------------------------- |
+//! | [`bitmap`]   | The 32-byte [little-endian bitmap], as bytes       |
+//! | [`ranges`]   | A sequence of inclusive `[start, end]` pairs       |
+//! | [`seq`]      | A sequence of the contained bytes, in order        |
+//! | [`text`]     | A string in the [text syntax]                      |
+//!
+//! Without any attribute, [`ByteSet`] uses [`text`] for human-readable formats
+//! such as JSON, and [`bitmap`] for binary formats such as bincode. Binary data
+//! written before this module was added is a sequence of the bytes in the set,
+//! and can be read with [`seq`].
+//!
+//! When deserializing from a self-describing format, every representation
+//! accepts all of the others, so changing the representation of a field does
+//! not break existing data. Bytes and sequences of integers are read as the
+//! bitmap by [`bitmap`] and as the bytes in the set by everything else,
+//! including the default.
+//!
+//! # Examples
+//!
+//! ```
+//! use byte_set::{byte_set, ByteSet};
+//! use serde_derive::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Token {
+//!     ident: ByteSet,
+//!
+//!     #[serde(with = "byte_set::serde::ranges")]
+//!     ident_ranges: ByteSet,
+//! }
+//!
+//! let ident = ByteSet::ASCII_ALPHANUMERIC.inserting(b'_');
+//! let token = Token {
+//!     ident,
+//!     ident_ranges: ident,
+//! };
+//!
+//! let json = serde_json::to_string(&token).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"ident":"0-9 A-Z _ a-z","ident_ranges":[[48,57],[65,90],[95,95],[97,122]]}"#,
+//! );
+//!
+//! // Sets written as a sequence of bytes are still read.
+//! let old = r#"{"ident":[48,49,95],"ident_ranges":[48,49,95]}"#;
+//! let token: Token = serde_json::from_str(old).unwrap();
+//! assert_eq!(token.ident, byte_set![b'0', b'1', b'_']);
+//! assert_eq!(token.ident_ranges, token.ident);
+//! ```
+//!
+//! [`serde`]:              https://docs.rs/serde
+//! [`ByteSet`]:            ../struct.ByteSet.html
+//! [little-endian bitmap]: ../struct.ByteSet.html#method.to_bitmap_le
+//! [text syntax]:          ../struct.ByteSet.html#method.from_str
+
+use crate::ByteSet;
+use ::serde::de::{self, Deserialize, Deserializer, SeqAccess, Unexpected};
+use core::{convert::TryFrom, fmt};
+
+pub mod bitmap;
+pub mod ranges;
+pub mod seq;
+pub mod text;
+
+/// The representation that a deserializer is expected to provide.
+#[derive(Clone, Copy)]
+pub(crate) enum Form {
+    Bitmap,
+    Ranges,
+    Seq,
+    Text,
+}
+
+/// Deserializes a [`ByteSet`] in any representation, preferring `form`.
+///
+/// Formats that are not self-describing are only asked for `form`.
+///
+/// [`ByteSet`]: ../struct.ByteSet.html
+pub(crate) fn deserialize<'de, D>(
+    deserializer: D,
+    form: Form,
+) -> Result<ByteSet, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let human_readable = deserializer.is_human_readable();
+    let visitor = ByteSetVisitor {
+        form,
+        human_readable,
+    };
+
+    if human_readable {
+        return deserializer.deserialize_any(visitor);
+    }
+
+    match form {
+        Form::Bitmap => deserializer.deserialize_bytes(visitor),
+        Form::Ranges | Form::Seq => deserializer.deserialize_seq(visitor),
+        Form::Text => deserializer.deserialize_str(visitor),
+    }
+}
+
+/// Inserts `start..=end` into `set`, failing if the range is decreasing.
+fn insert_range<E>(set: &mut ByteSet, start: u8, end: u8) -> Result<(), E>
+where
+    E: de::Error,
+{
+    if start > end {
+        return Err(E::custom(format_args!(
+            "decreasing byte range {}..={}",
+            start, end
+        )));
+    }
+    set.insert_all(ByteSet::from_range_inclusive(start..=end));
+    Ok(())
+}
+
+struct ByteSetVisitor {
+    form: Form,
+    human_readable: bool,
+}
+
+impl<'de> de::Visitor<'de> for ByteSetVisitor {
+    type Value = ByteSet;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a set of bytes as a string, bitmap, or sequence")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if let Form::Bitmap = self.form {
+            let bitmap = <[u8; 32]>::try_from(v)
+                .map_err(|_| E::invalid_length(v.len(), &"a 32-byte bitmap"))?;
+            return Ok(ByteSet::from_bitmap_le(bitmap));
+        }
+
+        Ok(ByteSet::from(v))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut set = ByteSet::new();
+
+        match self.form {
+            // A sequence of integers is the bitmap, but one that starts with a
+            // pair holds ranges.
+            Form::Bitmap if self.human_readable => match seq.next_element()? {
+                Some(Item::Byte(byte)) => {
+                    set = read_bitmap(&mut seq, Some(byte), &self)?;
+                }
+                Some(Item::Range(start, end)) => {
+                    insert_range(&mut set, start, end)?;
+                    insert_items(&mut set, &mut seq)?;
+                }
+                None => {}
+            },
+            Form::Bitmap => set = read_bitmap(&mut seq, None, &self)?,
+            _ if self.human_readable => insert_items(&mut set, &mut seq)?,
+            Form::Ranges => {
+                while let Some((start, end)) = seq.next_element()? {
+                    insert_range(&mut set, start, end)?;
+                }
+            }
+            Form::Seq | Form::Text => {
+                while let Some(byte) = seq.next_element::<u8>()? {
+                    set.insert(byte);
+                }
+            }
+        }
+
+        Ok(set)
+    }
+}
+
+/// Reads the 32 bytes of a little-endian bitmap from `seq`, where `first` is
+/// the first byte if it was already read.
+fn read_bitmap<'de, A>(
+    seq: &mut A,
+    first: Option<u8>,
+    expected: &dyn de::Expected,
+) -> Result<ByteSet, A::Error>
+where
+    A: SeqAccess<'de>,
+{
+    let mut bitmap = [0; 32];
+    let start = match first {
+        Some(byte) => {
+            bitmap[0] = byte;
+            1
+        }
+        None => 0,
+    };
+
+    for (i, byte) in bitmap.iter_mut().enumerate().skip(start) {
+        *byte = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(i, expected))?;
+    }
+    if seq.next_element::<de::IgnoredAny>()?.is_some() {
+        return Err(de::Error::invalid_length(33, expected));
+    }
+
+    Ok(ByteSet::from_bitmap_le(bitmap))
+}
+
+/// Inserts the remaining bytes and ranges of `seq` into `set`.
+fn insert_items<'de, A>(set: &mut ByteSet, seq: &mut A) -> Result<(), A::Error>
+where
+    A: SeqAccess<'de>,
+{
+    while let Some(item) = seq.next_element()? {
+        match item {
+            Item::Byte(byte) => set.insert(byte),
+            Item::Range(start, end) => insert_range(set, start, end)?,
+        }
+    }
+    Ok(())
+}
+
+/// An element of a sequence in a self-describing format: either a byte or an
+/// inclusive `[start, end]` range.
+enum Item {
+    Byte(u8),
+    Range(u8, u8),
+}
+
+impl<'de> Deserialize<'de> for Item {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ItemVisitor;
+
+        impl<'de> de::Visitor<'de> for ItemVisitor {
+            type Value = Item;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a byte or a [start, end] pair of bytes")
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                u8::try_from(v).map(Item::Byte).map_err(|_| {
+                    E::invalid_value(Unexpected::Unsigned(v), &self)
+                })
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                u8::try_from(v)
+                    .map(Item::Byte)
+                    .map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let start = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let end = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+                if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                    return Err(de::Error::invalid_length(3, &self));
+                }
+                Ok(Item::Range(start, end))
+            }
+        }
+
+        deserializer.deserialize_any(ItemVisitor)
+    }
+}
@@ -0,0 +1,41 @@
+//! Serializes a [`ByteSet`] as a sequence of inclusive `[start, end]` pairs.
+//!
+//! The pairs are the same as those of [`ranges`], so they are in ascending
+//! order and never overlap or touch. This is compact for sets made of a few
+//! runs, such as ASCII classes.
+//!
+//! When deserializing, pairs may be in any order and may overlap. In
+//! self-describing formats, single bytes may be mixed in with pairs, and the
+//! [`seq`] and [`text`] representations are also accepted.
+//!
+//! [`ByteSet`]: ../../struct.ByteSet.html
+//! [`ranges`]:  ../../struct.ByteSet.html#method.ranges
+//! [`seq`]:     ../seq/index.html
+//! [`text`]:    ../text/index.html
+
+use super::Form;
+use crate::ByteSet;
+use ::serde::{ser::SerializeSeq, Deserializer, Serializer};
+
+/// Serializes `set` as a sequence of inclusive `[start, end]` pairs.
+pub fn serialize<S>(set: &ByteSet, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let ranges = set.ranges();
+
+    let mut seq = serializer.serialize_seq(Some(ranges.len()))?;
+    for range in ranges {
+        seq.serialize_element(&range.into_inner())?;
+    }
+    seq.end()
+}
+
+/// Deserializes a set from a sequence of inclusive `[start, end]` pairs, or
+/// from any other representation in self-describing formats.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<ByteSet, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    super::deserialize(deserializer, Form::Ranges)
+}
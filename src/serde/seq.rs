@@ -0,0 +1,38 @@
+//! Serializes a [`ByteSet`] as a sequence of its bytes in ascending order.
+//!
+//! This was the only representation before the [`serde`](../index.html)
+//! module was added. It can be used to read binary data written by earlier
+//! versions, or to keep writing data in that form.
+//!
+//! Bytes are read as the bytes in the set. When deserializing from a
+//! self-describing format, the [`ranges`] and [`text`] representations are
+//! also accepted.
+//!
+//! [`ByteSet`]: ../../struct.ByteSet.html
+//! [`ranges`]:  ../ranges/index.html
+//! [`text`]:    ../text/index.html
+
+use super::Form;
+use crate::ByteSet;
+use ::serde::{ser::SerializeSeq, Deserializer, Serializer};
+
+/// Serializes `set` as a sequence of its bytes in ascending order.
+pub fn serialize<S>(set: &ByteSet, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut seq = serializer.serialize_seq(Some(set.len()))?;
+    for byte in *set {
+        seq.serialize_element(&byte)?;
+    }
+    seq.end()
+}
+
+/// Deserializes a set from a sequence of bytes, or from any other
+/// representation in self-describing formats.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<ByteSet, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    super::deserialize(deserializer, Form::Seq)
+}
@@ -0,0 +1,33 @@
+//! Serializes a [`ByteSet`] as a string in its [text syntax], such as
+//! `"0-9 A-Z _ a-z"`.
+//!
+//! This is the default for human-readable formats.
+//!
+//! When deserializing from a self-describing format, the [`ranges`] and
+//! [`seq`] representations are also accepted.
+//!
+//! [`ByteSet`]:   ../../struct.ByteSet.html
+//! [text syntax]: ../../struct.ByteSet.html#method.from_str
+//! [`ranges`]:    ../ranges/index.html
+//! [`seq`]:       ../seq/index.html
+
+use super::Form;
+use crate::ByteSet;
+use ::serde::{Deserializer, Serializer};
+
+/// Serializes `set` as a string in its text syntax.
+pub fn serialize<S>(set: &ByteSet, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_str(set)
+}
+
+/// Deserializes a set from a string in its text syntax, or from any other
+/// representation in self-describing formats.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<ByteSet, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    super::deserialize(deserializer, Form::Text)
+}
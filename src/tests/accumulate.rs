@@ -0,0 +1,121 @@
+use crate::ByteSet;
+use std::io;
+
+fn inputs() -> Vec<Vec<u8>> {
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    let mut random = |len: usize, modulus: u64| -> Vec<u8> {
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                ((state >> 33) % modulus) as u8
+            })
+            .collect()
+    };
+
+    vec![
+        vec![],
+        vec![7],
+        b"hello world".to_vec(),
+        random(4095, 200),
+        random(4097, 256),
+        random(10_000, 26),
+        (0..=u8::MAX).chain(0..=u8::MAX).collect(),
+    ]
+}
+
+#[test]
+fn block_edges() {
+    // Each byte lands in the first, a middle, and the last partial set of a
+    // block, and in the block after a full one.
+    for &byte in &[0, 255] {
+        for &len in &[2, 4095, 4096, 4097] {
+            let mut input = vec![64; len];
+
+            for &index in &[0, 1, len / 2, len - 1] {
+                input[index] = byte;
+                assert_eq!(
+                    ByteSet::from_slice(&input),
+                    byte_set![64, byte],
+                    "byte = {}, len = {}, index = {}",
+                    byte,
+                    len,
+                    index,
+                );
+                input[index] = 64;
+            }
+        }
+    }
+}
+
+#[test]
+fn from_slice() {
+    for input in inputs() {
+        let expected: ByteSet = input.iter().collect();
+
+        for start in 0..4.min(input.len()) {
+            let expected: ByteSet = input[start..].iter().collect();
+            assert_eq!(ByteSet::from_slice(&input[start..]), expected);
+        }
+        assert_eq!(ByteSet::from_slice(&input), expected);
+        assert_eq!(ByteSet::from(&input[..]), expected);
+    }
+}
+
+/// A reader that returns at most `max_len` bytes per read, and is interrupted
+/// every other read.
+struct ChoppyReader<'a> {
+    bytes: &'a [u8],
+    max_len: usize,
+    interrupt: bool,
+}
+
+impl io::Read for ChoppyReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.interrupt = !self.interrupt;
+        if self.interrupt {
+            return Err(io::ErrorKind::Interrupted.into());
+        }
+
+        let len = buf.len().min(self.max_len).min(self.bytes.len());
+        buf[..len].copy_from_slice(&self.bytes[..len]);
+        self.bytes = &self.bytes[len..];
+        Ok(len)
+    }
+}
+
+#[test]
+fn from_reader() {
+    for input in inputs() {
+        let expected: ByteSet = input.iter().collect();
+        assert_eq!(ByteSet::from_reader(&input[..]).unwrap(), expected);
+
+        let reader = ChoppyReader {
+            bytes: &input,
+            max_len: 3,
+            interrupt: false,
+        };
+        assert_eq!(ByteSet::from_reader(reader).unwrap(), expected);
+    }
+}
+
+#[test]
+fn from_reader_stops_when_full() {
+    let all: Vec<u8> = (0..=u8::MAX).collect();
+    let reader = io::Read::chain(&all[..], io::repeat(0));
+
+    assert_eq!(ByteSet::from_reader(reader).unwrap(), ByteSet::full());
+}
+
+#[test]
+fn from_reader_error() {
+    struct Broken;
+
+    impl io::Read for Broken {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::InvalidData.into())
+        }
+    }
+
+    let error = ByteSet::from_reader(Broken).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,142 @@
+use crate::{AsciiSet, ByteSet};
+use core::{convert::TryFrom, mem};
+
+fn sets() -> Vec<ByteSet> {
+    vec![
+        ByteSet::new(),
+        ByteSet::ASCII,
+        ByteSet::ASCII_DIGIT,
+        ByteSet::ASCII_WHITESPACE,
+        ByteSet::ASCII_PUNCTUATION,
+        byte_set![0, 127],
+        ByteSet::from(&b",; -"[..]),
+    ]
+}
+
+const STRS: &[&str] = &[
+    "",
+    "hello, world",
+    "  héllo wörld \n",
+    ",;α,β;;γ,",
+    "日本語 - 2024",
+    "0\u{7f}\u{80}é",
+];
+
+#[test]
+fn layout() {
+    assert_eq!(mem::size_of::<AsciiSet>(), 16);
+}
+
+#[test]
+fn constants() {
+    let pairs = [
+        (AsciiSet::ASCII_ALPHABETIC, ByteSet::ASCII_ALPHABETIC),
+        (AsciiSet::ASCII_UPPERCASE, ByteSet::ASCII_UPPERCASE),
+        (AsciiSet::ASCII_LOWERCASE, ByteSet::ASCII_LOWERCASE),
+        (AsciiSet::ASCII_ALPHANUMERIC, ByteSet::ASCII_ALPHANUMERIC),
+        (AsciiSet::ASCII_DIGIT, ByteSet::ASCII_DIGIT),
+        (AsciiSet::ASCII_HEXDIGIT, ByteSet::ASCII_HEXDIGIT),
+        (AsciiSet::ASCII_PUNCTUATION, ByteSet::ASCII_PUNCTUATION),
+        (AsciiSet::ASCII_GRAPHIC, ByteSet::ASCII_GRAPHIC),
+        (AsciiSet::ASCII_WHITESPACE, ByteSet::ASCII_WHITESPACE),
+        (AsciiSet::ASCII_CONTROL, ByteSet::ASCII_CONTROL),
+        (AsciiSet::full(), ByteSet::ASCII),
+        (AsciiSet::new(), ByteSet::new()),
+    ];
+
+    for &(ascii_set, byte_set) in &pairs {
+        assert_eq!(ByteSet::from(ascii_set), byte_set);
+        assert_eq!(ascii_set.len(), byte_set.len());
+    }
+}
+
+#[test]
+fn conversions() {
+    for set in sets() {
+        let ascii_set = AsciiSet::try_from(set).unwrap();
+
+        assert_eq!(ascii_set.to_byte_set(), set);
+        assert_eq!(ascii_set.into_iter().collect::<ByteSet>(), set);
+        assert_eq!((!ascii_set).to_byte_set(), ByteSet::ASCII - set);
+
+        for byte in 0..=255 {
+            assert_eq!(ascii_set.contains(byte), set.contains(byte));
+        }
+        for c in "aZ0 \u{7f}\u{80}éπ".chars() {
+            let expected = c.is_ascii() && set.contains(c as u8);
+            assert_eq!(ascii_set.contains_char(c), expected);
+        }
+
+        let with_non_ascii = set.inserting(0x80).inserting(0xFF);
+        let error = AsciiSet::try_from(with_non_ascii).unwrap_err();
+        assert_eq!(error.non_ascii(), byte_set![0x80, 0xFF]);
+    }
+
+    let error = AsciiSet::try_from(ByteSet::full()).unwrap_err();
+    assert_eq!(error.non_ascii(), !ByteSet::ASCII);
+    assert_eq!(
+        error.to_string(),
+        "set contains 128 non-ASCII bytes, starting at 0x80"
+    );
+}
+
+#[test]
+fn from_chars() {
+    let set = AsciiSet::from_chars("hello".chars()).unwrap();
+    assert_eq!(set.to_byte_set(), ByteSet::from(&b"ehlo"[..]));
+
+    assert_eq!(AsciiSet::from_chars("".chars()), Some(AsciiSet::new()));
+    assert_eq!(AsciiSet::from_chars("hé".chars()), None);
+    assert_eq!(AsciiSet::from_chars(Some('\u{80}')), None);
+}
+
+#[test]
+fn operators() {
+    for a in sets() {
+        for b in sets() {
+            let x = AsciiSet::try_from(a).unwrap();
+            let y = AsciiSet::try_from(b).unwrap();
+
+            assert_eq!((x | y).to_byte_set(), a | b);
+            assert_eq!((x & y).to_byte_set(), a & b);
+            assert_eq!((x ^ y).to_byte_set(), a ^ b);
+            assert_eq!((x - y).to_byte_set(), a - b);
+
+            assert_eq!(x.is_subset(&y), a.is_subset(&b));
+            assert_eq!(x.is_superset(&y), a.is_superset(&b));
+            assert_eq!(x.is_disjoint(&y), a.is_disjoint(&b));
+            assert_eq!(x.cmp(&y), a.cmp(&b));
+        }
+    }
+}
+
+#[test]
+fn str_helpers() {
+    for set in sets() {
+        let ascii_set = AsciiSet::try_from(set).unwrap();
+        let matches = |c: char| ascii_set.contains_char(c);
+
+        for &s in STRS {
+            assert_eq!(ascii_set.find(s), s.find(matches));
+            assert_eq!(ascii_set.rfind(s), s.rfind(matches));
+
+            assert_eq!(ascii_set.trim_matches(s), s.trim_matches(matches));
+            assert_eq!(
+                ascii_set.trim_start_matches(s),
+                s.trim_start_matches(matches)
+            );
+            assert_eq!(
+                ascii_set.trim_end_matches(s),
+                s.trim_end_matches(matches)
+            );
+
+            let parts: Vec<&str> = ascii_set.split(s).collect();
+            let expected: Vec<&str> = s.split(matches).collect();
+            assert_eq!(parts, expected);
+
+            let parts: Vec<&str> = ascii_set.split(s).rev().collect();
+            let expected: Vec<&str> = s.rsplit(matches).collect();
+            assert_eq!(parts, expected);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::ByteSet;
+
+fn sets() -> Vec<ByteSet> {
+    let mut sets = vec![
+        ByteSet::new(),
+        ByteSet::full(),
+        ByteSet::ASCII_ALPHANUMERIC,
+        ByteSet::from(&[0, 31, 32, 63, 64, 127, 128, 255][..]),
+    ];
+    sets.extend((0..=u8::MAX).map(ByteSet::from));
+    sets
+}
+
+#[test]
+fn layout() {
+    for set in sets() {
+        let le = set.to_bitmap_le();
+        let be = set.to_bitmap_be();
+        let u64s = set.to_u64_array();
+        let u128s = set.to_u128_array();
+
+        for byte in 0..=u8::MAX {
+            let n = byte as usize;
+            let contains = set.contains(byte);
+
+            assert_eq!(le[n / 8] & (1 << (n % 8)) != 0, contains);
+            assert_eq!(be[31 - n / 8] & (1 << (n % 8)) != 0, contains);
+            assert_eq!(u64s[n / 64] & (1 << (n % 64)) != 0, contains);
+            assert_eq!(u128s[n / 128] & (1 << (n % 128)) != 0, contains);
+        }
+    }
+}
+
+#[test]
+fn round_trip() {
+    for set in sets() {
+        assert_eq!(ByteSet::from_bitmap_le(set.to_bitmap_le()), set);
+        assert_eq!(ByteSet::from_bitmap_be(set.to_bitmap_be()), set);
+        assert_eq!(ByteSet::from_u64_array(set.to_u64_array()), set);
+        assert_eq!(ByteSet::from_u128_array(set.to_u128_array()), set);
+
+        let u64s: [u64; 4] = set.into();
+        let u128s: [u128; 2] = set.into();
+        assert_eq!(ByteSet::from(u64s), set);
+        assert_eq!(ByteSet::from(u128s), set);
+    }
+}
+
+#[test]
+fn is_const() {
+    const SET: ByteSet = ByteSet::from_bitmap_be(ByteSet::ASCII.to_bitmap_be());
+    const WORDS: [u128; 2] = SET.to_u128_array();
+
+    assert_eq!(SET, ByteSet::ASCII);
+    assert_eq!(WORDS, [u128::MAX, 0]);
+}
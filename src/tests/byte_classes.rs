@@ -0,0 +1,107 @@
+use crate::{ByteClasses, ByteSet};
+
+fn inputs() -> Vec<Vec<ByteSet>> {
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    let mut random_set = || {
+        let mut chunks = [0u64; 4];
+        for chunk in &mut chunks {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            *chunk = state;
+        }
+        ByteSet::from_u64_array(chunks)
+    };
+
+    vec![
+        vec![],
+        vec![ByteSet::new(), ByteSet::full()],
+        vec![ByteSet::ASCII_DIGIT, ByteSet::ASCII_ALPHANUMERIC],
+        vec![
+            ByteSet::ASCII_LOWERCASE,
+            ByteSet::ASCII_UPPERCASE,
+            ByteSet::ASCII_WHITESPACE,
+            ByteSet::from_byte(b'_'),
+            ByteSet::from_byte(0),
+            ByteSet::from_byte(255),
+        ],
+        (0..=255).map(ByteSet::from_byte).collect(),
+        (0..8).map(|_| random_set()).collect(),
+        (0..3).map(|_| random_set()).collect(),
+    ]
+}
+
+#[test]
+fn class_of() {
+    for sets in inputs() {
+        let classes = ByteClasses::from_sets(sets.iter().copied());
+
+        for a in 0..=255 {
+            for b in 0..=255 {
+                let same_class = classes.class_of(a) == classes.class_of(b);
+                let same_sets =
+                    sets.iter().all(|set| set.contains(a) == set.contains(b));
+
+                assert_eq!(same_class, same_sets, "{} and {}", a, b);
+            }
+        }
+    }
+}
+
+#[test]
+fn numbering() {
+    for sets in inputs() {
+        let classes = ByteClasses::from_sets(sets);
+
+        // Classes are numbered in order of their first byte.
+        let mut next = 0;
+        for byte in 0..=255 {
+            let class = classes.class_of(byte);
+            assert!(class as usize <= next);
+            if class as usize == next {
+                assert_eq!(classes.representative(class), Some(byte));
+                next += 1;
+            }
+        }
+
+        assert_eq!(classes.num_classes(), next);
+        assert_eq!(classes.representative(255).is_some(), next == 256);
+    }
+}
+
+#[test]
+fn members() {
+    for sets in inputs() {
+        let classes = ByteClasses::from_sets(sets);
+
+        let mut union = ByteSet::new();
+        for class in 0..classes.num_classes() {
+            let members = classes.members(class as u8);
+
+            assert!(!members.is_empty());
+            assert!(members.is_disjoint(&union));
+            assert_eq!(members.first(), classes.representative(class as u8),);
+
+            for byte in members {
+                assert_eq!(classes.class_of(byte) as usize, class);
+            }
+            union.insert_all(members);
+        }
+        assert_eq!(union, ByteSet::full());
+
+        if classes.num_classes() < 256 {
+            let class = classes.num_classes() as u8;
+            assert_eq!(classes.members(class), ByteSet::new());
+            assert_eq!(classes.representative(class), None);
+        }
+    }
+}
+
+#[test]
+fn single_class() {
+    let classes = ByteClasses::new();
+
+    assert_eq!(classes, ByteClasses::default());
+    assert_eq!(classes, ByteClasses::from_sets(vec![ByteSet::full()]));
+    assert_eq!(classes.num_classes(), 1);
+    assert_eq!(classes.members(0), ByteSet::full());
+    assert_eq!(classes.representatives(), ByteSet::from_byte(0));
+}
@@ -0,0 +1,137 @@
+use crate::{ByteCounts, ByteSet};
+
+fn naive_counts(bytes: &[u8]) -> [u64; 256] {
+    let mut counts = [0; 256];
+    for &byte in bytes {
+        counts[byte as usize] += 1;
+    }
+    counts
+}
+
+fn inputs() -> Vec<Vec<u8>> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let random: Vec<u8> = (0..5000)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (state >> 56) as u8
+        })
+        .collect();
+
+    vec![
+        vec![],
+        b"abracadabra".to_vec(),
+        vec![b'x'; 1023],
+        vec![b'x'; 4099],
+        random,
+    ]
+}
+
+#[test]
+fn counts() {
+    for input in inputs() {
+        let expected = naive_counts(&input);
+
+        let counts = ByteCounts::from_slice(&input);
+        assert_eq!(counts.as_array()[..], expected[..]);
+        assert_eq!(counts.total(), input.len() as u64);
+        assert_eq!(counts.is_empty(), input.is_empty());
+
+        let collected: ByteCounts = input.iter().collect();
+        assert_eq!(collected, counts);
+
+        for byte in 0..=u8::MAX {
+            assert_eq!(counts.count(byte), expected[byte as usize]);
+            assert_eq!(counts[byte], expected[byte as usize]);
+        }
+    }
+}
+
+#[test]
+fn sets() {
+    for input in inputs() {
+        let counts = ByteCounts::from_slice(&input);
+
+        assert_eq!(counts.support(), ByteSet::from(&input[..]));
+
+        for &threshold in &[0, 1, 2, 10, 100] {
+            let above = counts.above(threshold);
+            for byte in 0..=u8::MAX {
+                assert_eq!(above.contains(byte), counts[byte] > threshold);
+            }
+        }
+
+        let entries: Vec<(u8, u64)> = counts.iter().collect();
+        let expected: Vec<(u8, u64)> = counts
+            .support()
+            .into_iter()
+            .map(|byte| (byte, counts[byte]))
+            .collect();
+        assert_eq!(entries, expected);
+        assert_eq!(counts.iter().len(), counts.support().len());
+    }
+}
+
+#[test]
+fn top_k() {
+    for input in inputs() {
+        let counts = ByteCounts::from_slice(&input);
+
+        let mut ranked: Vec<u8> = counts.support().into_iter().collect();
+        ranked.sort_by(|&a, &b| counts[b].cmp(&counts[a]).then(a.cmp(&b)));
+
+        for &k in &[0, 1, 3, 255, 256, 1000] {
+            let expected: ByteSet = ranked.iter().take(k).cloned().collect();
+            assert_eq!(counts.top_k(k), expected, "k = {}", k);
+        }
+
+        assert_eq!(counts.most_common(), ranked.first().cloned());
+    }
+}
+
+#[test]
+fn rarest_in() {
+    let counts = ByteCounts::from_slice(b"aaabbc");
+
+    assert_eq!(counts.rarest_in(ByteSet::from(&b"ab"[..])), Some(b'b'));
+    assert_eq!(counts.rarest_in(ByteSet::from(&b"abc"[..])), Some(b'c'));
+    assert_eq!(counts.rarest_in(ByteSet::from(&b"az"[..])), Some(b'z'));
+    assert_eq!(counts.rarest_in(ByteSet::from(&b"yz"[..])), Some(b'y'));
+    assert_eq!(counts.rarest_in(ByteSet::new()), None);
+}
+
+#[test]
+fn arithmetic() {
+    let a = ByteCounts::from_slice(b"aab");
+    let b = ByteCounts::from_slice(b"abbc");
+
+    let sum = a.clone() + &b;
+    assert_eq!(sum, ByteCounts::from_slice(b"aababbc"));
+    assert_eq!(
+        sum.clone() + b.clone(),
+        ByteCounts::from_slice(b"aaaabbbbbcc")
+    );
+
+    let difference = a.clone() - &b;
+    assert_eq!(difference, ByteCounts::from_slice(b"a"));
+    assert_eq!(b.clone() - a.clone(), ByteCounts::from_slice(b"bc"));
+
+    let mut counts = ByteCounts::new();
+    counts += &a;
+    counts += b.clone();
+    assert_eq!(counts, sum);
+    counts -= &b;
+    assert_eq!(counts, a);
+    counts -= a.clone();
+    assert!(counts.is_empty());
+
+    counts.set_count(b'z', 7);
+    assert_eq!(counts.total(), 7);
+    counts.clear();
+    assert_eq!(counts, ByteCounts::default());
+}
+
+#[test]
+fn debug() {
+    let counts = ByteCounts::from_slice(b"aab");
+    assert_eq!(format!("{:?}", counts), "{97: 2, 98: 1}");
+}
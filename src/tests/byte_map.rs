@@ -0,0 +1,184 @@
+use super::common::{scrambled_bytes, BOUNDARY_BYTES};
+use crate::{ByteMap, ByteSet};
+use std::{cell::Cell, collections::BTreeMap, rc::Rc};
+
+/// Increments a shared counter when dropped.
+#[derive(Clone, Debug)]
+struct DropCounter(Rc<Cell<usize>>);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn matches_btree_map() {
+    let mut map = ByteMap::new();
+    let mut expected = BTreeMap::new();
+
+    for (i, key) in scrambled_bytes().enumerate() {
+        if i % 3 == 0 {
+            assert_eq!(map.remove(key), expected.remove(&key));
+        } else {
+            assert_eq!(map.insert(key, i), expected.insert(key, i));
+        }
+
+        assert_eq!(map.len(), expected.len());
+        assert_eq!(map.get(key), expected.get(&key));
+        assert_eq!(map.contains_key(key), expected.contains_key(&key));
+    }
+
+    let entries: Vec<(u8, &usize)> = map.iter().collect();
+    let expected_entries: Vec<(u8, &usize)> =
+        expected.iter().map(|(&k, v)| (k, v)).collect();
+    assert_eq!(entries, expected_entries);
+
+    let entries: Vec<(u8, &usize)> = map.iter().rev().collect();
+    let expected_entries: Vec<(u8, &usize)> =
+        expected.iter().rev().map(|(&k, v)| (k, v)).collect();
+    assert_eq!(entries, expected_entries);
+
+    let keys: ByteSet = expected.keys().collect();
+    assert_eq!(map.keys(), keys);
+
+    let values: Vec<&usize> = map.values().collect();
+    assert_eq!(values, expected.values().collect::<Vec<_>>());
+
+    let owned: Vec<(u8, usize)> = map.into_iter().collect();
+    assert_eq!(owned, expected.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn empty() {
+    let mut map: ByteMap<u8> = ByteMap::new();
+
+    assert!(map.is_empty());
+    assert_eq!(map.keys(), ByteSet::new());
+    assert_eq!(map.iter().next(), None);
+    assert_eq!(map.iter().next_back(), None);
+    assert_eq!(map.get(0), None);
+    assert_eq!(map.remove(255), None);
+    assert_eq!(map, ByteMap::default());
+}
+
+#[test]
+fn boundary_keys() {
+    for &key in BOUNDARY_BYTES {
+        let mut map = ByteMap::new();
+
+        assert_eq!(map.insert(key, "first"), None);
+        assert_eq!(map.insert(key, "second"), Some("first"));
+        assert_eq!(map.keys(), ByteSet::from(key));
+        assert_eq!(map.iter().collect::<Vec<_>>(), [(key, &"second")]);
+        assert_eq!(map.iter().rev().collect::<Vec<_>>(), [(key, &"second")]);
+
+        assert_eq!(map.remove(key), Some("second"));
+        assert!(map.is_empty());
+    }
+
+    let map: ByteMap<u8> = vec![(255, 1), (0, 0)].into_iter().collect();
+    assert_eq!(map.iter().next(), Some((0, &0)));
+    assert_eq!(map.iter().next_back(), Some((255, &1)));
+}
+
+#[test]
+fn full() {
+    let mut map: ByteMap<u8> = (0..=u8::MAX).map(|k| (k, !k)).collect();
+
+    assert_eq!(map.len(), 256);
+    assert_eq!(map.keys(), ByteSet::full());
+    assert_eq!(map.iter().len(), 256);
+
+    for key in 0..=u8::MAX {
+        assert_eq!(map.remove(key), Some(!key));
+    }
+    assert!(map.is_empty());
+}
+
+#[test]
+fn mutation() {
+    let mut map: ByteMap<u32> = (0..=u8::MAX).map(|k| (k, k as u32)).collect();
+
+    for (key, value) in map.iter_mut() {
+        *value += key as u32;
+    }
+    for value in map.values_mut().rev() {
+        *value += 1;
+    }
+
+    for key in 0..=u8::MAX {
+        assert_eq!(map[key], key as u32 * 2 + 1);
+    }
+}
+
+#[test]
+fn entry() {
+    let mut map = ByteMap::new();
+
+    for &byte in b"mississippi" {
+        *map.entry(byte).or_insert(0) += 1;
+    }
+    assert_eq!(map.get(b'm'), Some(&1));
+    assert_eq!(map.get(b'i'), Some(&4));
+    assert_eq!(map.get(b's'), Some(&4));
+    assert_eq!(map.get(b'p'), Some(&2));
+
+    map.entry(b'm').and_modify(|count| *count = 10).or_default();
+    map.entry(b'z').and_modify(|count| *count = 10).or_default();
+    assert_eq!(map.get(b'm'), Some(&10));
+    assert_eq!(map.get(b'z'), Some(&0));
+
+    match map.entry(b'p') {
+        crate::byte_map::Entry::Occupied(entry) => {
+            assert_eq!(entry.remove(), 2)
+        }
+        crate::byte_map::Entry::Vacant(_) => panic!("'p' is vacant"),
+    }
+    assert!(!map.contains_key(b'p'));
+}
+
+#[test]
+fn drops() {
+    let drops = Rc::new(Cell::new(0));
+    let value = || DropCounter(drops.clone());
+
+    let mut map = ByteMap::new();
+    for key in 0..100 {
+        map.insert(key, value());
+    }
+    assert_eq!(drops.get(), 0);
+
+    // Replacing and removing drop exactly one value each.
+    drop(map.insert(0, value()));
+    assert_eq!(drops.get(), 1);
+    drop(map.remove(1));
+    assert_eq!(drops.get(), 2);
+
+    let clone = map.clone();
+    drop(clone);
+    assert_eq!(drops.get(), 2 + 99);
+
+    // Partially consuming the owning iterator drops the rest.
+    let mut iter = map.into_iter();
+    drop(iter.next());
+    drop(iter.next_back());
+    assert_eq!(drops.get(), 2 + 99 + 2);
+    drop(iter);
+    assert_eq!(drops.get(), 2 + 99 + 99);
+}
+
+#[test]
+fn eq_and_debug() {
+    let a: ByteMap<&str> =
+        vec![(b'b', "bee"), (b'a', "ay")].into_iter().collect();
+    let mut b = ByteMap::new();
+    b.insert(b'a', "ay");
+    b.insert(b'b', "bee");
+
+    assert_eq!(a, b);
+    assert_eq!(format!("{:?}", a), r#"{97: "ay", 98: "bee"}"#);
+
+    b.insert(b'b', "be");
+    assert_ne!(a, b);
+}
@@ -10,7 +10,7 @@ fn len() {
 fn insert() {
     let mut set = ByteSet::new();
 
-    for byte in 0..=u8::max_value() {
+    for byte in 0..=u8::MAX {
         assert_not_contains!(set, byte);
 
         let copy = set;
@@ -27,7 +27,7 @@ fn insert() {
 fn remove() {
     let mut set = ByteSet::full();
 
-    for byte in 0..=u8::max_value() {
+    for byte in 0..=u8::MAX {
         assert_contains!(set, byte);
 
         let copy = set;
@@ -49,8 +49,8 @@ fn first() {
             assert_eq!(
                 set.first(),
                 first,
-                "First byte in {} is not {:?}",
-                set.fmt_binary(),
+                "First byte in {:#b} is not {:?}",
+                set,
                 first
             );
         }};
@@ -79,8 +79,8 @@ fn last() {
             assert_eq!(
                 set.last(),
                 last,
-                "Last byte in {} is not {:?}",
-                set.fmt_binary(),
+                "Last byte in {:#b} is not {:?}",
+                set,
                 last
             );
         }};
@@ -102,14 +102,14 @@ fn last() {
 
 #[test]
 fn from_open_ranges() {
-    for byte in 0..=u8::max_value() {
+    for byte in 0..=u8::MAX {
         let range_to = ..byte;
         let range_from = byte..;
         let range_to_i = ..=byte;
-        let set_to = ByteSet::from_range_to(range_to.clone());
+        let set_to = ByteSet::from_range_to(range_to);
         let set_from = ByteSet::from_range_from(range_from.clone());
-        let set_to_i = ByteSet::from_range_to_inclusive(range_to_i.clone());
-        for b in 0..=u8::max_value() {
+        let set_to_i = ByteSet::from_range_to_inclusive(range_to_i);
+        for b in 0..=u8::MAX {
             assert_eq!(range_to.contains(&b), set_to.contains(b));
             assert_eq!(range_from.contains(&b), set_from.contains(b));
             assert_eq!(range_to_i.contains(&b), set_to_i.contains(b));
@@ -119,16 +119,156 @@ fn from_open_ranges() {
 
 #[test]
 fn from_closed_ranges() {
-    for start in 0..=u8::max_value() {
-        for end in start..=u8::max_value() {
+    for start in 0..=u8::MAX {
+        for end in start..=u8::MAX {
             let range = start..end;
             let range_i = start..=end;
             let set = ByteSet::from_range(range.clone());
             let set_i = ByteSet::from_range_inclusive(range_i.clone());
-            for b in 0..=u8::max_value() {
+            for b in 0..=u8::MAX {
                 assert_eq!(range.contains(&b), set.contains(b));
                 assert_eq!(range_i.contains(&b), set_i.contains(b));
             }
         }
     }
 }
+
+#[test]
+fn rank_select() {
+    let sets = [
+        ByteSet::new(),
+        ByteSet::full(),
+        ByteSet::ASCII_ALPHANUMERIC,
+        ByteSet::from_range(100..200),
+        ByteSet::from(&[0, 63, 64, 127, 128, 191, 192, 255][..]),
+    ];
+
+    for set in &sets {
+        let bytes: Vec<u8> = set.into_iter().collect();
+
+        for byte in 0..=u8::MAX {
+            let rank = bytes.iter().filter(|&&b| b < byte).count();
+            assert_eq!(set.rank(byte), rank, "rank of {}", byte);
+
+            let index = bytes.iter().position(|&b| b == byte);
+            assert_eq!(set.index_of(byte), index, "index of {}", byte);
+        }
+
+        for n in 0..=256 {
+            assert_eq!(set.select(n), bytes.get(n).cloned(), "select {}", n);
+        }
+    }
+}
+
+#[test]
+fn debug() {
+    let cases = [
+        (ByteSet::new(), "{}"),
+        (ByteSet::full(), "{b'\\0'..=0xFF}"),
+        (
+            ByteSet::from(&b"'\\\t\r\n "[..]),
+            "{b'\\t'..=b'\\n', b'\\r', b' ', b'\\'', b'\\\\'}",
+        ),
+        (ByteSet::from_range_inclusive(0x7F..=0x81), "{0x7F..=0x81}"),
+        (ByteSet::ASCII_DIGIT.inserting(0xFF), "{b'0'..=b'9', 0xFF}"),
+    ];
+
+    for (set, expected) in &cases {
+        assert_eq!(format!("{:?}", set), *expected);
+    }
+}
+
+#[test]
+fn debug_alternate() {
+    let set = ByteSet::ASCII_GRAPHIC
+        .union(ByteSet::ASCII_CONTROL)
+        .removing(b'!');
+
+    assert_eq!(
+        format!("{:#?}", set),
+        "ByteSet {
+    ranges: {
+        b'\\0'..=0x1F,
+        b'\"'..=0x7F,
+    },
+    ascii_classes: [
+        ASCII_ALPHANUMERIC,
+        ASCII_CONTROL,
+    ],
+}"
+    );
+
+    let debug = format!("{:#?}", ByteSet::ASCII_HEXDIGIT.inserting(0x80));
+    assert_eq!(
+        debug,
+        "ByteSet {
+    ranges: {
+        b'0'..=b'9',
+        b'A'..=b'F',
+        b'a'..=b'f',
+        0x80,
+    },
+    ascii_classes: [
+        ASCII_HEXDIGIT,
+    ],
+}"
+    );
+}
+
+#[test]
+fn fmt_radix() {
+    let set = ByteSet::from(&[0, 2, 3, 64, 255][..]);
+
+    let binary = format!("{:b}", set);
+    assert_eq!(binary.len(), 256);
+    assert!(binary.starts_with("10"));
+    assert!(binary.ends_with(&format!("1{}1101", "0".repeat(60))));
+
+    let hex = format!("{:x}", set);
+    assert_eq!(hex, format!("8{}1{}d", "0".repeat(46), "0".repeat(15)));
+    assert_eq!(format!("{:X}", set), hex.to_uppercase());
+
+    // The same flags as integers.
+    let small = ByteSet::from(&[0, 2, 3, 7][..]);
+    let cases = [
+        (format!("{:b}", small), "10001101"),
+        (format!("{:#b}", small), "0b10001101"),
+        (format!("{:#x}", small), "0x8d"),
+        (format!("{:#X}", small), "0x8D"),
+        (format!("{:#06x}", small), "0x008d"),
+        (format!("{:>6x}", small), "    8d"),
+        (format!("{:*<6X}", small), "8D****"),
+        (format!("{:^#8x}", small), "  0x8d  "),
+        (format!("{:b}", ByteSet::new()), "0"),
+        (format!("{:#x}", ByteSet::new()), "0x0"),
+    ];
+
+    for (formatted, expected) in &cases {
+        assert_eq!(formatted, expected);
+    }
+
+    assert_eq!(format!("{:x}", ByteSet::full()), "f".repeat(64));
+}
+
+#[test]
+fn match_constant() {
+    fn name(set: ByteSet) -> &'static str {
+        match set {
+            ByteSet::ASCII_DIGIT => "digit",
+            ByteSet::ASCII_WHITESPACE => "whitespace",
+            _ => "other",
+        }
+    }
+
+    assert_eq!(name(ByteSet::ASCII_DIGIT), "digit");
+    assert_eq!(name(ByteSet::from(&b"\t\n\x0C\r "[..])), "whitespace");
+    assert_eq!(name(ByteSet::ASCII_DIGIT.inserting(b'_')), "other");
+}
+
+#[test]
+fn layout() {
+    use core::mem;
+
+    assert_eq!(mem::size_of::<ByteSet>(), 32);
+    assert_eq!(mem::align_of::<ByteSet>(), 32);
+}
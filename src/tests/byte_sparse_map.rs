@@ -0,0 +1,132 @@
+use super::common::{scrambled_bytes, BOUNDARY_BYTES};
+use crate::{ByteMap, ByteSet, ByteSparseMap};
+use std::collections::BTreeMap;
+
+#[test]
+fn matches_btree_map() {
+    let mut map = ByteSparseMap::new();
+    let mut expected = BTreeMap::new();
+
+    for (i, key) in scrambled_bytes().enumerate() {
+        if i % 3 == 0 {
+            assert_eq!(map.remove(key), expected.remove(&key));
+        } else {
+            assert_eq!(map.insert(key, i), expected.insert(key, i));
+        }
+
+        assert_eq!(map.len(), expected.len());
+        assert_eq!(map.get(key), expected.get(&key));
+        assert_eq!(map.contains_key(key), expected.contains_key(&key));
+    }
+
+    for (index, &key) in expected.keys().enumerate() {
+        assert_eq!(map.index_of(key), Some(index));
+    }
+
+    let entries: Vec<(u8, &usize)> = map.iter().collect();
+    let expected_entries: Vec<(u8, &usize)> =
+        expected.iter().map(|(&k, v)| (k, v)).collect();
+    assert_eq!(entries, expected_entries);
+
+    let entries: Vec<(u8, &usize)> = map.iter().rev().collect();
+    let expected_entries: Vec<(u8, &usize)> =
+        expected.iter().rev().map(|(&k, v)| (k, v)).collect();
+    assert_eq!(entries, expected_entries);
+
+    let keys: ByteSet = expected.keys().collect();
+    assert_eq!(map.keys(), keys);
+
+    let dense: ByteMap<usize> = map.clone().into();
+    assert_eq!(ByteSparseMap::from(dense), map);
+
+    let owned: Vec<(u8, usize)> = map.into_iter().collect();
+    assert_eq!(owned, expected.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn empty() {
+    let mut map: ByteSparseMap<u8> = ByteSparseMap::new();
+
+    assert!(map.is_empty());
+    assert_eq!(map.capacity(), 0);
+    assert_eq!(map.keys(), ByteSet::new());
+    assert_eq!(map.iter().next(), None);
+    assert_eq!(map.index_of(0), None);
+    assert_eq!(map.remove(255), None);
+    assert_eq!(map, ByteSparseMap::default());
+}
+
+#[test]
+fn boundary_keys() {
+    let mut map = ByteSparseMap::new();
+
+    // Inserting out of order keeps values sorted by key, so each insert lands
+    // at the rank of its key.
+    for &key in BOUNDARY_BYTES.iter().rev() {
+        map.insert(key, key);
+    }
+    assert_eq!(map.values().as_slice(), BOUNDARY_BYTES);
+
+    for (index, &key) in BOUNDARY_BYTES.iter().enumerate() {
+        assert_eq!(map.index_of(key), Some(index));
+    }
+    assert_eq!(map.index_of(2), None);
+
+    assert_eq!(map.remove(0), Some(0));
+    assert_eq!(map.index_of(255), Some(BOUNDARY_BYTES.len() - 2));
+    assert_eq!(map.remove(255), Some(255));
+    assert_eq!(map.values().as_slice(), &BOUNDARY_BYTES[1..11]);
+}
+
+#[test]
+fn full() {
+    let mut map: ByteSparseMap<u8> = (0..=u8::MAX).map(|k| (k, !k)).collect();
+
+    assert_eq!(map.len(), 256);
+    assert_eq!(map.keys(), ByteSet::full());
+    assert_eq!(map.index_of(255), Some(255));
+
+    for key in 0..=u8::MAX {
+        assert_eq!(map.remove(key), Some(!key));
+        if key < 255 {
+            // The least remaining key is always first.
+            assert_eq!(map.index_of(key + 1), Some(0));
+        }
+    }
+    assert!(map.is_empty());
+}
+
+#[test]
+fn mutation() {
+    let mut map: ByteSparseMap<u32> =
+        (0..=u8::MAX).step_by(3).map(|k| (k, k as u32)).collect();
+
+    for (key, value) in map.iter_mut() {
+        *value += key as u32;
+    }
+    for value in map.values_mut().rev() {
+        *value += 1;
+    }
+
+    for key in 0..=u8::MAX {
+        if key % 3 == 0 {
+            assert_eq!(map[key], key as u32 * 2 + 1);
+        } else {
+            assert_eq!(map.get(key), None);
+        }
+    }
+}
+
+#[test]
+fn into_iter_partial() {
+    let map: ByteSparseMap<String> = b"hello"
+        .iter()
+        .map(|&b| (b, (b as char).to_string()))
+        .collect();
+
+    let mut iter = map.into_iter();
+    assert_eq!(iter.next(), Some((b'e', "e".to_string())));
+    assert_eq!(iter.next_back(), Some((b'o', "o".to_string())));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(format!("{:?}", iter), r#"[(104, "h"), (108, "l")]"#);
+}
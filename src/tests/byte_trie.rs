@@ -0,0 +1,191 @@
+use crate::{ByteSet, ByteTrie};
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap},
+    hash::{Hash, Hasher},
+};
+
+fn keys() -> Vec<Vec<u8>> {
+    let mut state = 0xda94_2042_e4dd_58b5_u64;
+    let mut keys: Vec<Vec<u8>> = (0..300)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            let len = (state >> 60) as usize % 6;
+            (0..len)
+                .map(|i| b"abc\xff"[(state >> (8 * i)) as usize % 4])
+                .collect()
+        })
+        .collect();
+
+    keys.push(vec![]);
+    keys.push(b"/api".to_vec());
+    keys.push(b"/api/users".to_vec());
+    keys
+}
+
+fn build() -> (ByteTrie<usize>, BTreeMap<Vec<u8>, usize>) {
+    let mut trie = ByteTrie::new();
+    let mut model = BTreeMap::new();
+
+    for (i, key) in keys().into_iter().enumerate() {
+        assert_eq!(trie.insert(&key, i), model.insert(key, i));
+        assert_eq!(trie.len(), model.len());
+    }
+    (trie, model)
+}
+
+#[test]
+fn get() {
+    let (trie, model) = build();
+
+    for key in keys() {
+        assert_eq!(trie.get(&key), model.get(&key));
+        assert!(trie.contains_key(&key));
+
+        let mut missing = key.clone();
+        missing.push(b'z');
+        assert_eq!(trie.get(&missing), None);
+    }
+}
+
+#[test]
+fn iter() {
+    let (trie, model) = build();
+
+    let actual: Vec<(Vec<u8>, &usize)> = trie.iter().collect();
+    let expected: Vec<(Vec<u8>, &usize)> = model
+        .iter()
+        .map(|(key, value)| (key.clone(), value))
+        .collect();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn iter_prefix() {
+    let (trie, model) = build();
+
+    for prefix in keys().iter().chain(&[b"z".to_vec(), b"/ap".to_vec()]) {
+        let actual: Vec<(Vec<u8>, &usize)> = trie.iter_prefix(prefix).collect();
+        let expected: Vec<(Vec<u8>, &usize)> = model
+            .iter()
+            .filter(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| (key.clone(), value))
+            .collect();
+
+        assert_eq!(actual, expected, "{:?}", prefix);
+    }
+}
+
+#[test]
+fn next_bytes() {
+    let (trie, model) = build();
+
+    for prefix in keys().iter().chain(&[b"z".to_vec(), b"/ap".to_vec()]) {
+        let expected: ByteSet = model
+            .keys()
+            .filter(|key| key.len() > prefix.len() && key.starts_with(prefix))
+            .map(|key| key[prefix.len()])
+            .collect();
+
+        assert_eq!(trie.next_bytes(prefix), expected, "{:?}", prefix);
+    }
+}
+
+#[test]
+fn longest_prefix_match() {
+    let (trie, model) = build();
+
+    for key in keys()
+        .iter()
+        .chain(&[b"abcabcz".to_vec(), b"/api/x".to_vec()])
+    {
+        let expected = (0..=key.len())
+            .rev()
+            .find_map(|len| Some((&key[..len], model.get(&key[..len])?)));
+
+        assert_eq!(trie.longest_prefix_match(key), expected);
+    }
+
+    let mut trie = ByteTrie::new();
+    assert_eq!(trie.longest_prefix_match(b"abc"), None);
+
+    trie.insert(b"", 0);
+    assert_eq!(trie.longest_prefix_match(b"abc"), Some((&b""[..], &0)));
+}
+
+#[test]
+fn remove() {
+    let (mut trie, mut model) = build();
+
+    for key in keys() {
+        assert_eq!(trie.remove(&key), model.remove(&key));
+        assert_eq!(trie.len(), model.len());
+
+        let entries: Vec<(Vec<u8>, usize)> =
+            trie.iter().map(|(key, &value)| (key, value)).collect();
+        let expected: Vec<(Vec<u8>, usize)> =
+            model.clone().into_iter().collect();
+        assert_eq!(entries, expected);
+    }
+
+    // Removing every key also removes every node.
+    assert!(trie.is_empty());
+    assert_eq!(trie, ByteTrie::new());
+}
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn clone_eq_hash() {
+    let (trie, _) = build();
+    let clone = trie.clone();
+
+    assert_eq!(clone, trie);
+    assert_eq!(hash_of(&clone), hash_of(&trie));
+    assert!(clone.iter().eq(trie.iter()));
+
+    let mut other = trie.clone();
+    other.insert(b"abcabc", 0);
+    assert_ne!(other, trie);
+
+    let mut other = trie.clone();
+    *other.get_mut(b"/api").unwrap() += 1;
+    assert_ne!(other, trie);
+}
+
+#[test]
+fn long_key() {
+    // Long enough to overflow the stack if any operation recursed per byte.
+    let key = vec![b'a'; 300_000];
+    let prefix = &key[..150_000];
+
+    let mut trie = ByteTrie::new();
+    trie.insert(&key, 1);
+    trie.insert(prefix, 2);
+    assert_eq!(trie.get(&key), Some(&1));
+    assert_eq!(
+        trie.longest_prefix_match(&key[..200_000]),
+        Some((prefix, &2))
+    );
+
+    let clone = trie.clone();
+    assert_eq!(clone, trie);
+    assert_eq!(hash_of(&clone), hash_of(&trie));
+    drop(clone);
+
+    assert_eq!(trie.remove(prefix), Some(2));
+    assert_eq!(trie.get(&key), Some(&1));
+    assert_eq!(trie.remove(&key), Some(1));
+    assert_eq!(trie, ByteTrie::new());
+
+    trie.insert(&key, 3);
+    trie.insert(prefix, 4);
+    assert_eq!(trie.remove(&key), Some(3));
+    assert_eq!(trie.get(prefix), Some(&4));
+    assert_eq!(trie.next_bytes(prefix), ByteSet::new());
+    drop(trie);
+}
@@ -0,0 +1,105 @@
+use crate::{ByteSet, ClassPattern};
+
+fn naive_find(
+    sets: &[ByteSet],
+    haystack: &[u8],
+    start: usize,
+) -> Option<usize> {
+    (start..=haystack.len().checked_sub(sets.len())?).find(|&i| {
+        sets.iter()
+            .zip(&haystack[i..])
+            .all(|(set, &byte)| set.contains(byte))
+    })
+}
+
+fn patterns() -> Vec<Vec<ByteSet>> {
+    let digit = ByteSet::ASCII_DIGIT;
+    let ab = ByteSet::from(&b"ab"[..]);
+
+    vec![
+        vec![ByteSet::from_byte(b'a')],
+        vec![ByteSet::full()],
+        vec![digit, digit, digit, ByteSet::from_byte(b'-'), digit, digit],
+        vec![ByteSet::ASCII_UPPERCASE, ByteSet::ASCII_LOWERCASE],
+        vec![ab, ab, ByteSet::from_byte(b'b')],
+        vec![ab, ByteSet::new(), ab],
+        vec![ab; 63],
+        vec![ab; 64],
+    ]
+}
+
+fn haystacks() -> Vec<Vec<u8>> {
+    let mut state = 0x853c_49e6_748f_ea9b_u64;
+    let mut random = |alphabet: &[u8], len: usize| -> Vec<u8> {
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                alphabet[(state >> 33) as usize % alphabet.len()]
+            })
+            .collect()
+    };
+
+    vec![
+        vec![],
+        b"a".to_vec(),
+        b"call 555-01 or 555-02".to_vec(),
+        b"Hello World".to_vec(),
+        vec![b'a'; 200],
+        random(b"ab", 300),
+        random(b"abc", 300),
+        random(b"0123456789- ", 300),
+        random(b"aAbB ", 300),
+    ]
+}
+
+#[test]
+fn new() {
+    assert_eq!(ClassPattern::new(&[]), None);
+    assert_eq!(ClassPattern::new(&[ByteSet::full(); 65]), None);
+
+    for sets in patterns() {
+        let pattern = ClassPattern::new(&sets).unwrap();
+
+        assert_eq!(pattern.len(), sets.len());
+        for (i, &set) in sets.iter().enumerate() {
+            assert_eq!(pattern.get(i), Some(set));
+        }
+        assert_eq!(pattern.get(sets.len()), None);
+    }
+}
+
+#[test]
+fn find() {
+    for sets in patterns() {
+        let pattern = ClassPattern::new(&sets).unwrap();
+
+        for haystack in haystacks() {
+            let expected = naive_find(&sets, &haystack, 0);
+
+            assert_eq!(pattern.find(&haystack), expected);
+            assert_eq!(pattern.is_match(&haystack), expected.is_some());
+        }
+    }
+}
+
+#[test]
+fn find_iter() {
+    for sets in patterns() {
+        let pattern = ClassPattern::new(&sets).unwrap();
+
+        for haystack in haystacks() {
+            let mut expected = Vec::new();
+            let mut start = 0;
+            while let Some(index) = naive_find(&sets, &haystack, start) {
+                expected.push(index);
+                start = index + sets.len();
+            }
+
+            let mut iter = pattern.find_iter(&haystack);
+            let actual: Vec<usize> = iter.by_ref().collect();
+
+            assert_eq!(actual, expected);
+            assert_eq!(iter.next(), None);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::ByteSet;
+use std::cmp::Ordering;
+
+fn sets() -> Vec<ByteSet> {
+    let mut sets = vec![
+        ByteSet::new(),
+        ByteSet::full(),
+        ByteSet::ASCII_DIGIT,
+        ByteSet::ASCII_ALPHANUMERIC,
+        ByteSet::ASCII.not(),
+    ];
+
+    // Bytes around chunk boundaries, alone and with greater bytes.
+    for &byte in &[0, 1, 31, 32, 63, 64, 127, 128, 191, 192, 254, 255] {
+        let set = ByteSet::from_byte(byte);
+        sets.push(set);
+        sets.push(set.inserting(255));
+        sets.push(set.inserting(byte.wrapping_add(1)));
+        sets.push(ByteSet::from_range_from(byte..));
+    }
+
+    // Pseudo-random sets from a linear congruential generator.
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..32 {
+        let mut words = [0; 4];
+        for word in &mut words {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            *word = state;
+        }
+        sets.push(ByteSet::from_u64_array(words));
+    }
+
+    sets
+}
+
+#[test]
+fn cmp() {
+    let sets = sets();
+
+    for a in &sets {
+        for b in &sets {
+            let expected = a.into_iter().cmp(*b);
+            assert_eq!(a.cmp(b), expected, "{:?} cmp {:?}", a, b);
+            assert_eq!(a.partial_cmp(b), Some(expected));
+        }
+    }
+}
+
+#[test]
+fn cmp_by_len() {
+    let sets = sets();
+
+    for a in &sets {
+        for b in &sets {
+            let expected = a.len().cmp(&b.len()).then(a.cmp(b));
+            assert_eq!(a.cmp_by_len(b), expected, "{:?} cmp {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn cmp_colex() {
+    let sets = sets();
+
+    for a in &sets {
+        for b in &sets {
+            let greatest_diff = a.symmetric_difference(*b).last();
+            let expected = match greatest_diff {
+                None => Ordering::Equal,
+                Some(byte) if a.contains(byte) => Ordering::Greater,
+                Some(_) => Ordering::Less,
+            };
+            assert_eq!(a.cmp_colex(b), expected, "{:?} cmp {:?}", a, b);
+        }
+    }
+}
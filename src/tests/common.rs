@@ -0,0 +1,11 @@
+//! Fixtures shared by the test modules.
+
+/// Bytes at the start and end of every 32-bit and 64-bit chunk, including the
+/// `0` and `255` boundaries of the whole set.
+pub const BOUNDARY_BYTES: &[u8] =
+    &[0, 1, 31, 32, 63, 64, 127, 128, 191, 192, 254, 255];
+
+/// Bytes in a scrambled order, with repeats.
+pub fn scrambled_bytes() -> impl Iterator<Item = u8> {
+    (0..600u32).map(|i| (i.wrapping_mul(167) ^ (i >> 3)) as u8)
+}
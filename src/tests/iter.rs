@@ -7,7 +7,7 @@ fn collect_full() {
     let bytes: Vec<u8> = set.into_iter().collect();
     assert_eq!(bytes.len(), 256);
 
-    for b in 0..=u8::max_value() {
+    for b in 0..=u8::MAX {
         let i = b as usize;
 
         assert_eq!(bytes.get(i), Some(&b), "{:?} at {} is not {}", bytes, i, b);
@@ -21,7 +21,7 @@ fn collect_full_rev() {
     let bytes: Vec<u8> = set.into_iter().rev().collect();
     assert_eq!(bytes.len(), 256);
 
-    for b in 0..=u8::max_value() {
+    for b in 0..=u8::MAX {
         // Iterating in reverse, so flip the index.
         let i = 255 - b as usize;
 
@@ -52,7 +52,7 @@ fn ord() {
     let full = ByteSet::full().into_iter();
     assert_eq!(
         full.collect::<Vec<u8>>(),
-        (0..=u8::max_value()).collect::<Vec<u8>>(),
+        (0..=u8::MAX).collect::<Vec<u8>>(),
     );
 }
 
@@ -61,6 +61,6 @@ fn ord_rev() {
     let full = ByteSet::full().into_iter();
     assert_eq!(
         full.rev().collect::<Vec<u8>>(),
-        (0..=u8::max_value()).rev().collect::<Vec<u8>>(),
+        (0..=u8::MAX).rev().collect::<Vec<u8>>(),
     );
 }
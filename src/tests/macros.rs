@@ -2,12 +2,7 @@
 
 macro_rules! assert_not_contains {
     ($set:expr, $byte:expr) => {
-        assert!(
-            !$set.contains($byte),
-            "{} contains {}",
-            $set.fmt_binary(),
-            $byte
-        );
+        assert!(!$set.contains($byte), "{:#b} contains {}", $set, $byte);
     };
 }
 
@@ -15,8 +10,8 @@ macro_rules! assert_contains {
     ($set:expr, $byte:expr) => {
         assert!(
             $set.contains($byte),
-            "{} does not contain {}",
-            $set.fmt_binary(),
+            "{:#b} does not contain {}",
+            $set,
             $byte
         );
     };
@@ -27,8 +22,8 @@ macro_rules! assert_len {
         assert_eq!(
             $set.len(),
             $len,
-            "{} does not have {} bytes",
-            $set.fmt_binary(),
+            "{:#b} does not have {} bytes",
+            $set,
             $len
         );
     };
@@ -36,6 +31,6 @@ macro_rules! assert_len {
 
 macro_rules! assert_empty {
     ($set:expr) => {
-        assert!($set.is_empty(), "{} is not empty", $set.fmt_binary());
+        assert!($set.is_empty(), "{:#b} is not empty", $set);
     };
 }
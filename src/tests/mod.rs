@@ -1,5 +1,28 @@
 #[macro_use]
 mod macros;
 
+mod common;
+
+mod accumulate;
+mod ascii_set;
+mod bitmap;
+mod byte_classes;
+mod byte_counts;
+mod byte_map;
 mod byte_set;
+mod byte_sparse_map;
+mod byte_trie;
+mod class_pattern;
+mod cmp;
 mod iter;
+mod order;
+mod ranges;
+mod regex;
+mod search;
+mod searcher;
+#[cfg(feature = "serde")]
+mod serde;
+mod split;
+mod text;
+mod trim;
+mod vector;
@@ -0,0 +1,80 @@
+use crate::ByteSet;
+use std::{
+    collections::BTreeSet,
+    ops::{Bound, RangeBounds},
+};
+
+fn sets() -> Vec<ByteSet> {
+    vec![
+        ByteSet::new(),
+        ByteSet::full(),
+        ByteSet::ASCII_HEXDIGIT,
+        ByteSet::ASCII_WHITESPACE.not(),
+        ByteSet::from(&[0, 63, 64, 127, 128, 191, 192, 255][..]),
+    ]
+}
+
+fn bounds() -> Vec<Bound<u8>> {
+    let mut bounds = vec![Bound::Unbounded];
+    for &byte in &[0, 1, 48, 63, 64, 65, 127, 128, 200, 254, 255] {
+        bounds.push(Bound::Included(byte));
+        bounds.push(Bound::Excluded(byte));
+    }
+    bounds
+}
+
+#[test]
+fn range() {
+    for set in sets() {
+        let tree: BTreeSet<u8> = set.into_iter().collect();
+
+        for &start in &bounds() {
+            for &end in &bounds() {
+                let range = (start, end);
+                let bytes: Vec<u8> = set.range(range).collect();
+
+                // `BTreeSet::range` panics on decreasing ranges.
+                let expected: Vec<u8> = tree
+                    .iter()
+                    .cloned()
+                    .filter(|b| range.contains(b))
+                    .collect();
+
+                assert_eq!(bytes, expected, "{:?}", range);
+                assert_eq!(set.len_in(range), expected.len());
+            }
+        }
+    }
+}
+
+#[test]
+fn neighbors() {
+    for set in sets() {
+        for byte in 0..=u8::MAX {
+            let after = set.into_iter().find(|&b| b > byte);
+            assert_eq!(set.next_after(byte), after);
+
+            let before = set.into_iter().rev().find(|&b| b < byte);
+            assert_eq!(set.prev_before(byte), before);
+
+            let absent = (byte..=u8::MAX).find(|&b| !set.contains(b));
+            assert_eq!(set.next_absent_from(byte), absent);
+        }
+    }
+}
+
+#[test]
+fn split_off() {
+    for set in sets() {
+        for byte in 0..=u8::MAX {
+            let mut lower = set;
+            let upper = lower.split_off(byte);
+
+            let mut tree_lower: BTreeSet<u8> = set.into_iter().collect();
+            let tree_upper = tree_lower.split_off(&byte);
+
+            assert_eq!(lower, tree_lower);
+            assert_eq!(upper, tree_upper);
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::ByteSet;
+use core::ops::RangeInclusive;
+
+/// Returns the runs of `set` by checking each byte.
+fn naive_ranges(set: ByteSet) -> Vec<RangeInclusive<u8>> {
+    let mut ranges: Vec<RangeInclusive<u8>> = Vec::new();
+
+    for byte in set {
+        match ranges.last_mut() {
+            Some(range) if *range.end() as usize + 1 == byte as usize => {
+                *range = *range.start()..=byte;
+            }
+            _ => ranges.push(byte..=byte),
+        }
+    }
+    ranges
+}
+
+fn sets() -> Vec<ByteSet> {
+    let mut sets = vec![
+        ByteSet::new(),
+        ByteSet::full(),
+        ByteSet::ASCII_CONTROL,
+        ByteSet::ASCII_ALPHANUMERIC,
+        ByteSet::ASCII_PUNCTUATION,
+        ByteSet::from_range(60..70),
+        ByteSet::from_range_inclusive(0..=127),
+        ByteSet::from_range_from(128..),
+        ByteSet::from(&[0, 63, 64, 65, 127, 128, 191, 192, 255][..]),
+    ];
+
+    let mut alternating = ByteSet::new();
+    alternating
+        .as_raw_bytes_mut()
+        .copy_from_slice(&[0b0101_0101; 32]);
+    sets.push(alternating);
+    sets.push(alternating.not());
+    sets
+}
+
+#[test]
+fn ranges() {
+    for set in sets() {
+        let expected = naive_ranges(set);
+
+        let ranges: Vec<_> = set.ranges().collect();
+        assert_eq!(ranges, expected, "{:?}", set);
+        assert_eq!(set.ranges().len(), expected.len());
+
+        let mut reversed: Vec<_> = set.ranges().rev().collect();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+
+        // Alternate between both ends.
+        let mut iter = set.ranges();
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        while let Some(range) = iter.next() {
+            front.push(range);
+            match iter.next_back() {
+                Some(range) => back.push(range),
+                None => break,
+            }
+        }
+        front.extend(back.into_iter().rev());
+        assert_eq!(front, expected);
+
+        let gaps: Vec<_> = set.gaps().collect();
+        assert_eq!(gaps, naive_ranges(set.not()));
+
+        assert_eq!(ByteSet::from_ranges(set.ranges()), set);
+        assert_eq!(ByteSet::from_ranges(set.gaps()), set.not());
+    }
+}
+
+#[test]
+fn as_range() {
+    for set in sets() {
+        let ranges = naive_ranges(set);
+        let expected = if ranges.len() == 1 {
+            Some(ranges[0].clone())
+        } else {
+            None
+        };
+
+        assert_eq!(set.as_range(), expected, "{:?}", set);
+        assert_eq!(set.is_contiguous(), expected.is_some());
+    }
+
+    for byte in 0..=u8::MAX {
+        assert_eq!(ByteSet::from(byte).as_range(), Some(byte..=byte));
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{ByteSet, ParseErrorReason};
+
+#[test]
+fn to_regex_class() {
+    let cases = [
+        (ByteSet::new(), r"[^\x00-\xFF]"),
+        (ByteSet::full(), r"[\x00-\xFF]"),
+        (ByteSet::ASCII_DIGIT, "[0-9]"),
+        (ByteSet::ASCII_DIGIT.not(), "[^0-9]"),
+        (ByteSet::from(&b"]-^\\["[..]), r"[\-\[-\^]"),
+        (ByteSet::from(&b"]^"[..]), r"[\]\^]"),
+        (ByteSet::from(&b" \t"[..]), r"[\x09 ]"),
+    ];
+
+    for (set, expected) in &cases {
+        assert_eq!(set.to_regex_class(), *expected);
+    }
+}
+
+#[test]
+fn round_trip() {
+    let mut sets = vec![
+        ByteSet::new(),
+        ByteSet::full(),
+        ByteSet::ASCII_PUNCTUATION,
+        ByteSet::ASCII_GRAPHIC.not(),
+    ];
+    for byte in 0..=u8::MAX {
+        sets.push(ByteSet::from(byte));
+        sets.push(ByteSet::from(byte).not());
+    }
+
+    for set in sets {
+        let class = set.to_regex_class();
+        assert_eq!(ByteSet::from_regex_class(&class), Ok(set), "{}", class);
+    }
+}
+
+#[test]
+fn from_regex_class() {
+    let word = ByteSet::ASCII_ALPHANUMERIC.inserting(b'_');
+    let cases = [
+        ("[]]", ByteSet::from(b']')),
+        ("[^]]", ByteSet::from(b']').not()),
+        ("[-a]", ByteSet::from(&b"-a"[..])),
+        ("[a-]", ByteSet::from(&b"-a"[..])),
+        ("[a-c-]", ByteSet::from(&b"abc-"[..])),
+        (r"[\n\t\x41]", ByteSet::from(&b"\n\tA"[..])),
+        (r"[\.\]]", ByteSet::from(&b".]"[..])),
+        (r"[\w]", word),
+        (r"[\W]", word.not()),
+        (r"[\d\s]", ByteSet::from(&b"0123456789 \t\n\x0B\x0C\r"[..])),
+        ("[[:word:]]", word),
+        ("[[:space:]]", ByteSet::from(&b" \t\n\x0B\x0C\r"[..])),
+        (
+            "[[:upper:][:digit:]]",
+            ByteSet::from_regex_class("[A-Z0-9]").unwrap(),
+        ),
+        ("[^[:print:]]", ByteSet::ASCII_GRAPHIC.inserting(b' ').not()),
+        ("[[a]", ByteSet::from(&b"[a"[..])),
+        (r"[\d-]", ByteSet::ASCII_DIGIT.inserting(b'-')),
+        (r"[-\d]", ByteSet::ASCII_DIGIT.inserting(b'-')),
+    ];
+
+    for (class, expected) in &cases {
+        assert_eq!(
+            ByteSet::from_regex_class(class),
+            Ok(*expected),
+            "{}",
+            class
+        );
+    }
+}
+
+#[test]
+fn from_regex_class_error() {
+    let cases = [
+        ("", 1, ParseErrorReason::MissingOpenBracket),
+        ("a", 1, ParseErrorReason::MissingOpenBracket),
+        ("[", 2, ParseErrorReason::MissingCloseBracket),
+        ("[a", 3, ParseErrorReason::MissingCloseBracket),
+        ("[]", 3, ParseErrorReason::MissingCloseBracket),
+        ("[^a-z", 6, ParseErrorReason::MissingCloseBracket),
+        ("[a]b", 4, ParseErrorReason::UnexpectedChar('b')),
+        ("[é]", 2, ParseErrorReason::UnexpectedChar('é')),
+        ("[[:nope:]]", 2, ParseErrorReason::UnknownClass),
+        ("[[:alpha]", 2, ParseErrorReason::UnknownClass),
+        (r"[\q]", 2, ParseErrorReason::InvalidEscape),
+        (r"[\xZZ]", 2, ParseErrorReason::InvalidEscape),
+        (r"[a-\d]", 2, ParseErrorReason::ClassInRange),
+        (r"[\d-z]", 2, ParseErrorReason::ClassInRange),
+        (r"[a\W-z]", 3, ParseErrorReason::ClassInRange),
+        ("[[:digit:]-z]", 2, ParseErrorReason::ClassInRange),
+        (
+            "[z-a]",
+            2,
+            ParseErrorReason::DecreasingRange {
+                start: b'z',
+                end: b'a',
+            },
+        ),
+    ];
+
+    for &(class, column, reason) in &cases {
+        let error = ByteSet::from_regex_class(class).unwrap_err();
+        assert_eq!(error.column(), column, "{}", class);
+        assert_eq!(error.reason(), reason, "{}", class);
+    }
+}
@@ -0,0 +1,197 @@
+use crate::ByteSet;
+
+/// Haystacks covering empty input, partial words, multiple words, and inputs
+/// long enough for vectors, starting at every offset within a word.
+fn haystacks() -> Vec<Vec<u8>> {
+    let stepped = |len: usize| -> Vec<u8> {
+        (0..len).map(|i| (i * 37 + 11) as u8).collect()
+    };
+
+    let mut haystacks: Vec<Vec<u8>> = (0..=20).map(stepped).collect();
+    haystacks.push((0..=u8::MAX).collect());
+    haystacks.push((0..=u8::MAX).rev().collect());
+    haystacks.push(b"hello, world! 123\r\n\tabc".to_vec());
+
+    let long = stepped(300);
+    for start in 0..8 {
+        haystacks.push(long[start..].to_vec());
+        haystacks.push(long[start..start + 77].to_vec());
+    }
+
+    // Matches only at either end of a long run.
+    let mut run = vec![b'x'; 1000];
+    run.push(b'0');
+    haystacks.push(run.clone());
+    run.reverse();
+    haystacks.push(run);
+
+    haystacks
+}
+
+fn sets() -> Vec<ByteSet> {
+    vec![
+        ByteSet::new(),
+        ByteSet::full(),
+        ByteSet::ASCII_DIGIT,
+        ByteSet::ASCII_WHITESPACE,
+        ByteSet::ASCII_PUNCTUATION,
+        !ByteSet::ASCII,
+        byte_set![0],
+        byte_set![b'0'],
+        byte_set![0, 255],
+        byte_set![b'x', b'\n'],
+        byte_set![b' ', b',', 128],
+    ]
+}
+
+#[test]
+fn find_in() {
+    for set in sets() {
+        for haystack in haystacks() {
+            let contains = |&b: &u8| set.contains(b);
+
+            assert_eq!(
+                set.find_in(&haystack),
+                haystack.iter().position(contains),
+                "{:?} in {:?}",
+                set,
+                haystack,
+            );
+            assert_eq!(
+                set.rfind_in(&haystack),
+                haystack.iter().rposition(contains),
+                "{:?} in {:?}",
+                set,
+                haystack,
+            );
+        }
+    }
+}
+
+#[test]
+fn find_not_in() {
+    for set in sets() {
+        for haystack in haystacks() {
+            let not_contains = |&b: &u8| !set.contains(b);
+
+            assert_eq!(
+                set.find_not_in(&haystack),
+                haystack.iter().position(not_contains),
+                "{:?} in {:?}",
+                set,
+                haystack,
+            );
+            assert_eq!(
+                set.rfind_not_in(&haystack),
+                haystack.iter().rposition(not_contains),
+                "{:?} in {:?}",
+                set,
+                haystack,
+            );
+        }
+    }
+}
+
+#[test]
+fn find_iter() {
+    for set in sets() {
+        for haystack in haystacks() {
+            let expected: Vec<usize> = haystack
+                .iter()
+                .enumerate()
+                .filter(|(_, &b)| set.contains(b))
+                .map(|(i, _)| i)
+                .collect();
+
+            let forward: Vec<usize> = set.find_iter(&haystack).collect();
+            assert_eq!(forward, expected, "{:?} in {:?}", set, haystack);
+
+            let mut backward: Vec<usize> =
+                set.find_iter(&haystack).rev().collect();
+            backward.reverse();
+            assert_eq!(backward, expected, "{:?} in {:?}", set, haystack);
+        }
+    }
+}
+
+#[test]
+fn count_in() {
+    for set in sets() {
+        for haystack in haystacks() {
+            // Slicing into the same allocation starts the haystack at
+            // addresses that are not word-aligned.
+            for start in 0..haystack.len().min(8) {
+                let haystack = &haystack[start..];
+                let expected =
+                    haystack.iter().filter(|&&b| set.contains(b)).count();
+
+                assert_eq!(
+                    set.count_in(haystack),
+                    expected,
+                    "{:?} in {:?}",
+                    set,
+                    haystack,
+                );
+                assert_eq!(
+                    set.find_iter(haystack).count(),
+                    expected,
+                    "{:?} in {:?}",
+                    set,
+                    haystack,
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn validate() {
+    for set in sets() {
+        for haystack in haystacks() {
+            let invalid = haystack.iter().position(|&b| !set.contains(b));
+
+            match set.validate(&haystack) {
+                Ok(()) => assert_eq!(invalid, None),
+                Err(error) => {
+                    assert_eq!(Some(error.position()), invalid);
+                    assert_eq!(error.byte(), haystack[error.position()]);
+                }
+            }
+
+            assert_eq!(set.all_in(&haystack), invalid.is_none());
+            assert_eq!(
+                set.any_in(&haystack),
+                haystack.iter().any(|&b| set.contains(b)),
+            );
+        }
+    }
+}
+
+#[test]
+fn validate_long() {
+    let set = ByteSet::ASCII_ALPHANUMERIC;
+
+    // The invalid byte is neither at the start nor the end of a word, and
+    // comes after enough valid bytes to use the vector kernels.
+    for &position in &[131, 8 * 40 + 3] {
+        let mut input = vec![b'a'; 1000];
+        input[position] = b'-';
+
+        let error = set.validate(&input).unwrap_err();
+        assert_eq!(error.position(), position);
+        assert_eq!(error.byte(), b'-');
+
+        assert!(!set.all_in(&input));
+        assert!(set.all_in(&input[..position]));
+        assert!(set.all_in(&input[position + 1..]));
+
+        assert!(byte_set![b'-'].any_in(&input));
+        assert!(!byte_set![b'-'].any_in(&input[..position]));
+    }
+}
+
+#[test]
+fn invalid_byte_display() {
+    let error = byte_set![b'a'].validate(b"aa\xFF").unwrap_err();
+    assert_eq!(error.to_string(), "invalid byte 0xFF at position 2");
+}
@@ -0,0 +1,105 @@
+use crate::{searcher::Isa, ByteSearcher, ByteSet};
+
+/// Haystacks covering lengths around each vector width.
+fn haystacks() -> Vec<Vec<u8>> {
+    let mut haystacks: Vec<Vec<u8>> = (0..=100)
+        .map(|len| (0..len).map(|i| (i * 37 + 11) as u8).collect())
+        .collect();
+    haystacks.push((0..=u8::MAX).collect());
+    haystacks.push((0..=u8::MAX).rev().collect());
+    haystacks.push(vec![0; 77]);
+    haystacks.push(vec![255; 77]);
+    haystacks
+}
+
+fn sets() -> Vec<ByteSet> {
+    vec![
+        ByteSet::new(),
+        ByteSet::full(),
+        byte_set![0],
+        byte_set![255],
+        byte_set![0, 128],
+        byte_set![b'\r', b'\n', b'\t'],
+        byte_set![1, 2, 3, 4],
+        ByteSet::ASCII_DIGIT,
+        ByteSet::ASCII_WHITESPACE,
+        ByteSet::ASCII_PUNCTUATION,
+        !ByteSet::ASCII,
+        !ByteSet::ASCII_ALPHANUMERIC,
+        ByteSet::from_range_inclusive(0x70..=0x90),
+    ]
+}
+
+#[test]
+fn find() {
+    for isa in Isa::all_detected() {
+        for set in sets() {
+            let searcher = ByteSearcher::with_isa(set, isa);
+
+            for haystack in haystacks() {
+                let contains = |&b: &u8| set.contains(b);
+
+                assert_eq!(
+                    searcher.find(&haystack),
+                    haystack.iter().position(contains),
+                    "{:?} in {:?}",
+                    searcher,
+                    haystack,
+                );
+                assert_eq!(
+                    searcher.rfind(&haystack),
+                    haystack.iter().rposition(contains),
+                    "{:?} in {:?}",
+                    searcher,
+                    haystack,
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn count() {
+    for isa in Isa::all_detected() {
+        for set in sets() {
+            let searcher = ByteSearcher::with_isa(set, isa);
+
+            for haystack in haystacks() {
+                assert_eq!(
+                    searcher.count(&haystack),
+                    haystack.iter().filter(|&&b| set.contains(b)).count(),
+                    "{:?} in {:?}",
+                    searcher,
+                    haystack,
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn find_iter() {
+    for isa in Isa::all_detected() {
+        for set in sets() {
+            let searcher = ByteSearcher::with_isa(set, isa);
+
+            for haystack in haystacks() {
+                let expected: Vec<usize> = haystack
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &b)| set.contains(b))
+                    .map(|(i, _)| i)
+                    .collect();
+
+                let forward: Vec<usize> =
+                    searcher.find_iter(&haystack).collect();
+                assert_eq!(forward, expected, "{:?}", searcher);
+
+                let mut backward: Vec<usize> =
+                    searcher.find_iter(&haystack).rev().collect();
+                backward.reverse();
+                assert_eq!(backward, expected, "{:?}", searcher);
+            }
+        }
+    }
+}
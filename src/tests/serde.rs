@@ -0,0 +1,253 @@
+use crate::ByteSet;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_test::{
+    assert_de_tokens, assert_de_tokens_error, assert_tokens, Compact,
+    Configure, Readable, Token,
+};
+
+/// Defines a wrapper around `ByteSet` that uses `crate::serde::$module`.
+macro_rules! with_module {
+    ($name:ident, $module:ident) => {
+        #[derive(Debug, PartialEq)]
+        struct $name(ByteSet);
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                crate::serde::$module::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                crate::serde::$module::deserialize(deserializer).map($name)
+            }
+        }
+    };
+}
+
+with_module!(Bitmap, bitmap);
+with_module!(Ranges, ranges);
+with_module!(Seq, seq);
+with_module!(Text, text);
+
+const IDENT: ByteSet = ByteSet::ASCII_ALPHANUMERIC.inserting(b'_');
+const IDENT_BITMAP: [u8; 32] = IDENT.to_bitmap_le();
+
+/// Returns the tokens of `set` as a sequence of its bytes.
+fn seq_tokens(set: ByteSet) -> Vec<Token> {
+    let mut tokens = vec![Token::Seq {
+        len: Some(set.len()),
+    }];
+    tokens.extend(set.into_iter().map(Token::U8));
+    tokens.push(Token::SeqEnd);
+    tokens
+}
+
+#[test]
+fn default() {
+    assert_tokens(&IDENT.readable(), &[Token::Str("0-9 A-Z _ a-z")]);
+    assert_tokens(&ByteSet::new().readable(), &[Token::Str("")]);
+
+    assert_tokens(&IDENT.compact(), &[Token::Bytes(&IDENT_BITMAP)]);
+    assert_tokens(&ByteSet::new().compact(), &[Token::Bytes(&[0; 32])]);
+}
+
+#[test]
+fn default_reads_bytes() {
+    // Data written before the `serde` module was added.
+    let bytes = b"_azAZ09";
+    let set = ByteSet::from(&bytes[..]);
+
+    assert_de_tokens(&set.readable(), &[Token::Bytes(bytes)]);
+    assert_de_tokens(&Seq(set).compact(), &[Token::Bytes(bytes)]);
+    assert_de_tokens(&Seq(IDENT).compact(), &seq_tokens(IDENT));
+
+    // A 32-byte slice is not mistaken for a bitmap in self-describing formats.
+    let set = ByteSet::from(&IDENT_BITMAP[..]);
+    assert_de_tokens(&set.readable(), &[Token::Bytes(&IDENT_BITMAP)]);
+
+    assert_de_tokens_error::<Compact<ByteSet>>(
+        &[Token::Bytes(bytes)],
+        "invalid length 7, expected a 32-byte bitmap",
+    );
+}
+
+#[test]
+fn default_accepts_all_forms() {
+    let set = IDENT.readable();
+
+    assert_de_tokens(&set, &[Token::Str("_ a-z A-Z 0-9")]);
+    assert_de_tokens(&set, &seq_tokens(IDENT));
+
+    // Ranges may be out of order and mixed with single bytes.
+    assert_de_tokens(
+        &set,
+        &[
+            Token::Seq { len: None },
+            Token::Tuple { len: 2 },
+            Token::U8(b'a'),
+            Token::U8(b'z'),
+            Token::TupleEnd,
+            Token::U8(b'_'),
+            Token::Seq { len: Some(2) },
+            Token::U64(u64::from(b'0')),
+            Token::U64(u64::from(b'9')),
+            Token::SeqEnd,
+            Token::Tuple { len: 2 },
+            Token::I32(i32::from(b'A')),
+            Token::I32(i32::from(b'Z')),
+            Token::TupleEnd,
+            Token::SeqEnd,
+        ],
+    );
+}
+
+#[test]
+fn bitmap() {
+    const SET: ByteSet = byte_set![0, 9, 255];
+    const BITMAP: [u8; 32] = SET.to_bitmap_le();
+    let set = SET;
+
+    assert_tokens(&Bitmap(set).readable(), &[Token::Bytes(&BITMAP)]);
+    assert_tokens(&Bitmap(set).compact(), &[Token::Bytes(&BITMAP)]);
+
+    // A sequence of integers is the bitmap rather than the bytes.
+    let mut seq = vec![Token::Seq { len: Some(32) }];
+    seq.extend(BITMAP.iter().cloned().map(Token::U8));
+    seq.push(Token::SeqEnd);
+    assert_de_tokens(&Bitmap(set).readable(), &seq);
+
+    assert_de_tokens_error::<Readable<Bitmap>>(
+        &[Token::Bytes(&[0; 31])],
+        "invalid length 31, expected a 32-byte bitmap",
+    );
+
+    assert_de_tokens(&Bitmap(set).readable(), &[Token::Str(r"\0 \t \xff")]);
+}
+
+#[test]
+fn bitmap_accepts_other_forms() {
+    let sets = [ByteSet::new(), ByteSet::full(), IDENT, byte_set![0, 9, 255]];
+
+    for &set in &sets {
+        let forms = [
+            serde_json::to_string(&Bitmap(set)).unwrap(),
+            serde_json::to_string(&Ranges(set)).unwrap(),
+            serde_json::to_string(&Text(set)).unwrap(),
+        ];
+
+        for json in &forms {
+            let read: Bitmap = serde_json::from_str(json).unwrap();
+            assert_eq!(read, Bitmap(set), "{}", json);
+        }
+    }
+
+    // Single bytes may follow the first pair, as with `ranges`.
+    assert_de_tokens(
+        &Bitmap(byte_set![b'0', b'1', b'_']).readable(),
+        &[
+            Token::Seq { len: None },
+            Token::Tuple { len: 2 },
+            Token::U8(b'0'),
+            Token::U8(b'1'),
+            Token::TupleEnd,
+            Token::U8(b'_'),
+            Token::SeqEnd,
+        ],
+    );
+
+    // Integers after the first one are still bitmap bytes.
+    assert_de_tokens_error::<Readable<Bitmap>>(
+        &[
+            Token::Seq { len: None },
+            Token::U8(0),
+            Token::Tuple { len: 2 },
+        ],
+        "invalid type: sequence, expected u8",
+    );
+}
+
+#[test]
+fn ranges() {
+    let tokens = [
+        Token::Seq { len: Some(4) },
+        Token::Tuple { len: 2 },
+        Token::U8(b'0'),
+        Token::U8(b'9'),
+        Token::TupleEnd,
+        Token::Tuple { len: 2 },
+        Token::U8(b'A'),
+        Token::U8(b'Z'),
+        Token::TupleEnd,
+        Token::Tuple { len: 2 },
+        Token::U8(b'_'),
+        Token::U8(b'_'),
+        Token::TupleEnd,
+        Token::Tuple { len: 2 },
+        Token::U8(b'a'),
+        Token::U8(b'z'),
+        Token::TupleEnd,
+        Token::SeqEnd,
+    ];
+
+    assert_tokens(&Ranges(IDENT).readable(), &tokens);
+    assert_tokens(&Ranges(IDENT).compact(), &tokens);
+
+    assert_de_tokens(&Ranges(IDENT).readable(), &[Token::Str("0-9A-Z_a-z")]);
+}
+
+#[test]
+fn seq() {
+    let set = ByteSet::from(&b"abc"[..]);
+    let tokens = [
+        Token::Seq { len: Some(3) },
+        Token::U8(b'a'),
+        Token::U8(b'b'),
+        Token::U8(b'c'),
+        Token::SeqEnd,
+    ];
+
+    assert_tokens(&Seq(set).readable(), &tokens);
+    assert_tokens(&Seq(set).compact(), &tokens);
+
+    assert_de_tokens(&Seq(set).compact(), &[Token::Bytes(b"cab")]);
+}
+
+#[test]
+fn text() {
+    let set = ByteSet::from(&b"\0-\\"[..]);
+
+    assert_tokens(&Text(set).readable(), &[Token::Str(r"\0 \- \\")]);
+    assert_tokens(&Text(set).compact(), &[Token::Str(r"\0 \- \\")]);
+
+    assert_de_tokens(&Text(set).readable(), &[Token::Bytes(b"\\-\0")]);
+}
+
+#[test]
+fn errors() {
+    assert_de_tokens_error::<Readable<ByteSet>>(
+        &[Token::Str("a-")],
+        "missing end of range at column 3",
+    );
+    assert_de_tokens_error::<Readable<ByteSet>>(
+        &[
+            Token::Seq { len: Some(1) },
+            Token::Tuple { len: 2 },
+            Token::U8(b'z'),
+            Token::U8(b'a'),
+            Token::TupleEnd,
+        ],
+        "decreasing byte range 122..=97",
+    );
+    assert_de_tokens_error::<Readable<ByteSet>>(
+        &[Token::Seq { len: Some(1) }, Token::U16(256)],
+        "invalid value: integer `256`, expected a byte or a [start, end] pair \
+         of bytes",
+    );
+}
@@ -0,0 +1,126 @@
+use crate::ByteSet;
+
+fn haystacks() -> Vec<&'static [u8]> {
+    vec![
+        b"",
+        b",",
+        b",,",
+        b"a",
+        b"a,",
+        b",a",
+        b"a,b",
+        b"a,,b",
+        b",a,b,",
+        b"  hello \t world\n",
+        b"one,two;three  four;;",
+        b"a long haystack, with many; separators,,, that spans words",
+    ]
+}
+
+fn sets() -> Vec<ByteSet> {
+    vec![
+        ByteSet::new(),
+        ByteSet::full(),
+        byte_set![b','],
+        byte_set![b',', b';'],
+        ByteSet::ASCII_WHITESPACE,
+        ByteSet::ASCII_PUNCTUATION | ByteSet::ASCII_WHITESPACE,
+    ]
+}
+
+#[test]
+fn split() {
+    for set in sets() {
+        for haystack in haystacks() {
+            let pred = |b: &u8| set.contains(*b);
+
+            let expected: Vec<&[u8]> = haystack.split(pred).collect();
+            let actual: Vec<&[u8]> = set.split(haystack).collect();
+            assert_eq!(actual, expected, "{:?} in {:?}", set, haystack);
+
+            let expected: Vec<&[u8]> = haystack.split(pred).rev().collect();
+            let actual: Vec<&[u8]> = set.split(haystack).rev().collect();
+            assert_eq!(actual, expected, "{:?} in {:?}", set, haystack);
+
+            let expected: Vec<&[u8]> = haystack.rsplit(pred).collect();
+            let actual: Vec<&[u8]> = set.rsplit(haystack).collect();
+            assert_eq!(actual, expected, "{:?} in {:?}", set, haystack);
+        }
+    }
+}
+
+#[test]
+fn split_alternating() {
+    for set in sets() {
+        for haystack in haystacks() {
+            let pred = |b: &u8| set.contains(*b);
+
+            let mut expected = haystack.split(pred);
+            let mut actual = set.split(haystack);
+            loop {
+                let item = actual.next();
+                assert_eq!(item, expected.next());
+
+                let item_back = actual.next_back();
+                assert_eq!(item_back, expected.next_back());
+
+                if item.is_none() && item_back.is_none() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn splitn() {
+    for set in sets() {
+        for haystack in haystacks() {
+            for n in 0..6 {
+                let pred = |b: &u8| set.contains(*b);
+
+                let expected: Vec<&[u8]> = haystack.splitn(n, pred).collect();
+                let actual: Vec<&[u8]> = set.splitn(n, haystack).collect();
+                assert_eq!(actual, expected, "{:?} in {:?}", set, haystack);
+            }
+        }
+    }
+}
+
+#[test]
+fn split_inclusive() {
+    for set in sets() {
+        for haystack in haystacks() {
+            let pred = |b: &u8| set.contains(*b);
+
+            let expected: Vec<&[u8]> = haystack.split_inclusive(pred).collect();
+            let actual: Vec<&[u8]> = set.split_inclusive(haystack).collect();
+            assert_eq!(actual, expected, "{:?} in {:?}", set, haystack);
+
+            let expected: Vec<&[u8]> =
+                haystack.split_inclusive(pred).rev().collect();
+            let actual: Vec<&[u8]> =
+                set.split_inclusive(haystack).rev().collect();
+            assert_eq!(actual, expected, "{:?} in {:?}", set, haystack);
+        }
+    }
+}
+
+#[test]
+fn tokens() {
+    for set in sets() {
+        for haystack in haystacks() {
+            let expected: Vec<&[u8]> = haystack
+                .split(|b| set.contains(*b))
+                .filter(|token| !token.is_empty())
+                .collect();
+
+            let actual: Vec<&[u8]> = set.tokens(haystack).collect();
+            assert_eq!(actual, expected, "{:?} in {:?}", set, haystack);
+
+            let mut actual: Vec<&[u8]> = set.tokens(haystack).rev().collect();
+            actual.reverse();
+            assert_eq!(actual, expected, "{:?} in {:?}", set, haystack);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{ByteSet, ParseErrorReason};
+
+#[test]
+fn display() {
+    let cases = [
+        (ByteSet::new(), ""),
+        (ByteSet::full(), r"\0-\xff"),
+        (ByteSet::ASCII_DIGIT, "0-9"),
+        (ByteSet::ASCII_CONTROL, r"\0-\x1f \x7f"),
+        (ByteSet::ASCII_WHITESPACE, r"\t \n \x0c \r \x20"),
+        (ByteSet::from(&b"\\-ab"[..]), r"\- \\ a b"),
+        (ByteSet::from(&b"xyz"[..]), "x-z"),
+    ];
+
+    for (set, expected) in &cases {
+        assert_eq!(set.to_string(), *expected);
+    }
+}
+
+#[test]
+fn round_trip() {
+    let mut sets = vec![
+        ByteSet::ASCII_ALPHANUMERIC,
+        ByteSet::ASCII_PUNCTUATION,
+        ByteSet::ASCII_GRAPHIC.not(),
+    ];
+    for byte in 0..=u8::MAX {
+        sets.push(ByteSet::from(byte));
+        sets.push(ByteSet::from(byte).not());
+    }
+
+    for set in sets {
+        let text = set.to_string();
+        assert_eq!(text.parse(), Ok(set), "{:?}", text);
+    }
+}
+
+#[test]
+fn parse() {
+    let cases = [
+        ("", ByteSet::new()),
+        (" \t\n", ByteSet::new()),
+        ("abc", ByteSet::from(&b"abc"[..])),
+        ("a-cx-z", ByteSet::from(&b"abcxyz"[..])),
+        ("z-z", ByteSet::from(b'z')),
+        (r"\x41-\x5A", ByteSet::ASCII_UPPERCASE),
+    ];
+
+    for (text, expected) in &cases {
+        assert_eq!(text.parse(), Ok(*expected), "{:?}", text);
+    }
+}
+
+#[test]
+fn parse_error() {
+    let cases = [
+        ("a-", 3, ParseErrorReason::MissingRangeEnd),
+        ("a- b", 3, ParseErrorReason::MissingRangeEnd),
+        ("-a", 1, ParseErrorReason::UnexpectedChar('-')),
+        ("a--b", 3, ParseErrorReason::UnexpectedChar('-')),
+        ("ab é", 4, ParseErrorReason::UnexpectedChar('é')),
+        ("a\x7f", 2, ParseErrorReason::UnexpectedChar('\x7f')),
+        (r"a\", 2, ParseErrorReason::InvalidEscape),
+        (r"\q", 1, ParseErrorReason::InvalidEscape),
+        (r"\x4", 1, ParseErrorReason::InvalidEscape),
+        (r"\x+1", 1, ParseErrorReason::InvalidEscape),
+        (
+            "a-z 9-0",
+            5,
+            ParseErrorReason::DecreasingRange {
+                start: b'9',
+                end: b'0',
+            },
+        ),
+    ];
+
+    for &(text, column, reason) in &cases {
+        let error = text.parse::<ByteSet>().unwrap_err();
+        assert_eq!(error.column(), column, "{:?}", text);
+        assert_eq!(error.reason(), reason, "{:?}", text);
+    }
+
+    let error = "9-0".parse::<ByteSet>().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "range end 0x30 is less than start 0x39 at column 1"
+    );
+}
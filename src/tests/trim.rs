@@ -0,0 +1,73 @@
+use crate::ByteSet;
+
+fn strs() -> Vec<&'static str> {
+    vec![
+        "",
+        " ",
+        "   ",
+        "a",
+        " a ",
+        "\t\r\n hello world \n\r\t",
+        "  héllo wörld  ",
+        ",;,,value;;,",
+        "ünïcödé",
+    ]
+}
+
+fn ascii_sets() -> Vec<ByteSet> {
+    vec![
+        ByteSet::new(),
+        ByteSet::ASCII,
+        ByteSet::ASCII_WHITESPACE,
+        byte_set![b',', b';'],
+        ByteSet::ASCII_ALPHABETIC,
+    ]
+}
+
+#[test]
+fn trim() {
+    for set in ascii_sets().into_iter().chain(Some(ByteSet::full())) {
+        for s in strs() {
+            let haystack = s.as_bytes();
+            let not_contains = |&b: &u8| !set.contains(b);
+
+            let start = haystack.iter().position(not_contains);
+            let end = haystack.iter().rposition(not_contains).map(|i| i + 1);
+
+            let trimmed_start = &haystack[start.unwrap_or(haystack.len())..];
+            let trimmed_end = &haystack[..end.unwrap_or(0)];
+            let trimmed = match (start, end) {
+                (Some(start), Some(end)) => &haystack[start..end],
+                _ => &[],
+            };
+
+            assert_eq!(set.trim_start(haystack), trimmed_start);
+            assert_eq!(set.trim_end(haystack), trimmed_end);
+            assert_eq!(set.trim(haystack), trimmed);
+        }
+    }
+}
+
+#[test]
+fn trim_str() {
+    for set in ascii_sets() {
+        for s in strs() {
+            let pred = |c: char| c.is_ascii() && set.contains(c as u8);
+
+            assert_eq!(set.trim_str(s), Some(s.trim_matches(pred)));
+            assert_eq!(set.trim_start_str(s), Some(s.trim_start_matches(pred)));
+            assert_eq!(set.trim_end_str(s), Some(s.trim_end_matches(pred)));
+        }
+    }
+}
+
+#[test]
+fn trim_str_non_ascii() {
+    for &set in &[ByteSet::full(), byte_set![0x80], !ByteSet::ASCII] {
+        for s in strs() {
+            assert_eq!(set.trim_str(s), None);
+            assert_eq!(set.trim_start_str(s), None);
+            assert_eq!(set.trim_end_str(s), None);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! Checks that the vector backend selected for this target matches the
+//! portable `const` methods.
+
+use crate::{byte_set::vector, ByteSet};
+
+fn sets() -> Vec<ByteSet> {
+    let mut sets = vec![
+        ByteSet::new(),
+        ByteSet::full(),
+        byte_set![0],
+        byte_set![255],
+        ByteSet::ASCII,
+        !ByteSet::ASCII,
+        ByteSet::from(&[0, 31, 32, 63, 64, 127, 128, 191, 192, 255][..]),
+    ];
+
+    // Pseudo-random sets from a linear congruential generator, along with
+    // subsets and supersets of them so that `is_subset` is not always `false`.
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    let mut random_set = || {
+        let mut words = [0; 4];
+        for word in &mut words {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            *word = state;
+        }
+        ByteSet::from_u64_array(words)
+    };
+    for _ in 0..32 {
+        let (a, b) = (random_set(), random_set());
+        sets.extend(vec![a, a.intersection(b), a.union(b)]);
+    }
+
+    sets
+}
+
+#[test]
+fn unary() {
+    for set in sets() {
+        assert_eq!(vector::not(&set).0, set.not().0, "{:?}", set);
+        assert_eq!(vector::len(&set), set.len(), "{:?}", set);
+        assert_eq!(vector::len(&set), set.into_iter().count(), "{:?}", set);
+    }
+}
+
+#[test]
+fn binary() {
+    let sets = sets();
+
+    for a in &sets {
+        for b in &sets {
+            let (a, b) = (*a, *b);
+            let msg = format!("{:?}, {:?}", a, b);
+
+            assert_eq!(vector::union(&a, &b).0, a.union(b).0, "{}", msg);
+            assert_eq!(
+                vector::intersection(&a, &b).0,
+                a.intersection(b).0,
+                "{}",
+                msg
+            );
+            assert_eq!(
+                vector::difference(&a, &b).0,
+                a.difference(b).0,
+                "{}",
+                msg
+            );
+            assert_eq!(
+                vector::symmetric_difference(&a, &b).0,
+                a.symmetric_difference(b).0,
+                "{}",
+                msg
+            );
+
+            assert_eq!(vector::eq(&a, &b), a.eq(&b), "{}", msg);
+            assert_eq!(vector::eq(&a, &b), a.0 == b.0, "{}", msg);
+            assert_eq!(vector::is_subset(&a, &b), a._is_subset(&b), "{}", msg);
+            assert_eq!(
+                vector::is_disjoint(&a, &b),
+                a.intersection(b).is_empty(),
+                "{}",
+                msg
+            );
+        }
+    }
+}
+
+#[test]
+fn operators() {
+    let sets = sets();
+
+    for a in &sets {
+        for b in &sets {
+            let (a, b) = (*a, *b);
+
+            assert_eq!((a | b).0, a.union(b).0);
+            assert_eq!((a & b).0, a.intersection(b).0);
+            assert_eq!((a - b).0, a.difference(b).0);
+            assert_eq!((a ^ b).0, a.symmetric_difference(b).0);
+
+            let mut c = a;
+            c.insert_all(b);
+            assert_eq!(c.0, a.union(b).0);
+
+            let mut c = a;
+            c.remove_all(b);
+            assert_eq!(c.0, a.difference(b).0);
+
+            assert_eq!(a.contains_any(&b), !a.intersection(b).is_empty());
+            assert_eq!(a.is_strict_subset(&b), a.ne(&b) && a._is_subset(&b));
+        }
+    }
+}